@@ -0,0 +1,104 @@
+use alloc::string::{String, ToString};
+use core::error::Error;
+use core::fmt;
+
+use crate::types::NumberError;
+
+/// The crate-wide error for fallible polynomial and number operations.
+///
+/// Building a single [`Number`](crate::types::Number) fails with the narrower
+/// [`NumberError`] instead: `Number::try_new`, `Number::reciprocal`,
+/// `Number::from_str_radix`, `BigNumber::try_new` and deserializing. `?` converts
+/// a `NumberError` into this type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolysolveError {
+    DivisionByZero,
+    NoConvergence,
+    NotFinite,
+    NotInteger,
+    Overflow,
+    ParseError(String),
+    ZeroPolynomial,
+}
+
+impl fmt::Display for PolysolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolysolveError::DivisionByZero => write!(f, "division by zero"),
+            PolysolveError::NoConvergence => write!(f, "iteration did not converge"),
+            PolysolveError::NotFinite => write!(f, "NaN or infinite float"),
+            PolysolveError::NotInteger => write!(f, "expected an integer"),
+            PolysolveError::Overflow => write!(f, "Number overflow"),
+            PolysolveError::ParseError(message) => write!(f, "invalid polynomial: {}", message),
+            PolysolveError::ZeroPolynomial => write!(f, "undefined for the zero polynomial"),
+        }
+    }
+}
+
+impl Error for PolysolveError {}
+
+impl From<NumberError> for PolysolveError {
+    fn from(value: NumberError) -> Self {
+        match value {
+            NumberError::ZeroDenominator | NumberError::DivisionByZero => {
+                PolysolveError::DivisionByZero
+            }
+            NumberError::Invalid => PolysolveError::ParseError(value.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            PolysolveError::DivisionByZero.to_string(),
+            "division by zero"
+        );
+        assert_eq!(
+            PolysolveError::NoConvergence.to_string(),
+            "iteration did not converge"
+        );
+        assert_eq!(
+            PolysolveError::NotInteger.to_string(),
+            "expected an integer"
+        );
+        assert_eq!(PolysolveError::Overflow.to_string(), "Number overflow");
+        assert_eq!(
+            PolysolveError::ParseError("empty input".into()).to_string(),
+            "invalid polynomial: empty input"
+        );
+        assert_eq!(
+            PolysolveError::ZeroPolynomial.to_string(),
+            "undefined for the zero polynomial"
+        );
+    }
+
+    #[test]
+    fn from_number_error() {
+        assert_eq!(
+            PolysolveError::from(NumberError::ZeroDenominator),
+            PolysolveError::DivisionByZero
+        );
+        assert_eq!(
+            PolysolveError::from(NumberError::DivisionByZero),
+            PolysolveError::DivisionByZero
+        );
+        assert_eq!(
+            PolysolveError::from(NumberError::Invalid).to_string(),
+            "invalid polynomial: invalid number"
+        );
+
+        fn invert(n: crate::types::Number) -> Result<crate::types::Number, PolysolveError> {
+            Ok(n.reciprocal()?)
+        }
+        assert_eq!(
+            invert(crate::types::Number::zero()),
+            Err(PolysolveError::DivisionByZero)
+        );
+    }
+}
@@ -1 +1,8 @@
-mod types;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// a library has no business writing to its caller's terminal
+#![deny(clippy::print_stdout, clippy::print_stderr, clippy::dbg_macro)]
+
+extern crate alloc;
+
+pub mod error;
+pub mod types;
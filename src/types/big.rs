@@ -0,0 +1,411 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul, Neg};
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+use super::{Number, NumberError, PolynomialFunction, Rational};
+use crate::error::PolysolveError;
+
+// not imported, so its methods don't shadow the num-traits ones on BigInt
+impl super::Backing for BigInt {
+    fn from_i64(value: i64) -> Self {
+        BigInt::from(value)
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        self / rhs
+    }
+
+    fn rem(&self, rhs: &Self) -> Self {
+        self % rhs
+    }
+
+    /// Infinite, with the sign of `self`, when out of range.
+    fn to_f64(&self) -> f64 {
+        ToPrimitive::to_f64(self).unwrap_or(if Signed::is_negative(self) {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        })
+    }
+
+    fn is_zero(&self) -> bool {
+        Zero::is_zero(self)
+    }
+
+    fn gcd(&self, rhs: &Self) -> Self {
+        Integer::gcd(self, rhs)
+    }
+}
+
+/// An arbitrary-precision rational, for exact work where `Number` would overflow.
+pub type BigNumber = Rational<BigInt>;
+
+impl BigNumber {
+    /// Panics if `denominator` is zero; see [`BigNumber::try_new`].
+    pub fn new(numerator: impl Into<BigInt>, denominator: impl Into<BigInt>) -> Self {
+        Self::ratio(numerator.into(), denominator.into())
+    }
+
+    pub fn try_new(
+        numerator: impl Into<BigInt>,
+        denominator: impl Into<BigInt>,
+    ) -> Result<Self, NumberError> {
+        let denominator = denominator.into();
+        if Zero::is_zero(&denominator) {
+            return Err(NumberError::ZeroDenominator);
+        }
+        Ok(Self::new(numerator, denominator))
+    }
+
+    /// The `Number` with this value, if it fits.
+    pub fn to_number(&self) -> Option<Number> {
+        Some(Number::ratio(
+            self.numer().to_i64()?,
+            self.denom().to_i64()?,
+        ))
+    }
+}
+
+impl From<Number> for BigNumber {
+    fn from(value: Number) -> Self {
+        BigNumber::new(*value.numer(), *value.denom())
+    }
+}
+
+/// A polynomial with `BigNumber` coefficients, stored densely from the constant
+/// term up with no trailing zeros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigPolynomialFunction {
+    coeffs: Vec<BigNumber>,
+}
+
+impl BigPolynomialFunction {
+    /// Index `i` of `coeffs` is the coefficient of `x^i`.
+    pub fn from_coeffs(coeffs: &[BigNumber]) -> BigPolynomialFunction {
+        let mut coeffs = coeffs.to_vec();
+        while coeffs.last().is_some_and(|c| !c.not_zero()) {
+            coeffs.pop();
+        }
+        BigPolynomialFunction { coeffs }
+    }
+
+    /// The monic polynomial `(x - r1)(x - r2)...` with exactly the given roots.
+    pub fn from_roots(roots: &[BigNumber]) -> BigPolynomialFunction {
+        roots.iter().fold(
+            BigPolynomialFunction::from_coeffs(&[BigNumber::one()]),
+            |acc, r| acc * BigPolynomialFunction::from_coeffs(&[-r.clone(), BigNumber::one()]),
+        )
+    }
+
+    pub fn degree(&self) -> Option<i32> {
+        self.coeffs.len().checked_sub(1).map(|d| d as i32)
+    }
+
+    /// Ascending by degree, the same layout `from_coeffs` takes.
+    pub fn coefficients(&self) -> &[BigNumber] {
+        &self.coeffs
+    }
+
+    pub fn coefficient(&self, degree: i32) -> BigNumber {
+        usize::try_from(degree)
+            .ok()
+            .and_then(|d| self.coeffs.get(d))
+            .cloned()
+            .unwrap_or_else(BigNumber::zero)
+    }
+
+    /// Exact evaluation by Horner's scheme.
+    pub fn evaluate_exact(&self, x: &BigNumber) -> BigNumber {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(BigNumber::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// The distinct rational roots, ascending, by the rational root theorem on the
+    /// polynomial with denominators cleared. Candidates come from trial division, so
+    /// this slows down on constant terms with very large prime factors.
+    ///
+    /// Like [`PolynomialFunction::roots`], fails with
+    /// [`PolysolveError::ZeroPolynomial`] for the zero polynomial. Nothing else
+    /// fails, since `BigNumber` cannot overflow.
+    pub fn roots(&self) -> Result<Vec<BigNumber>, PolysolveError> {
+        let mut roots = BTreeSet::new();
+
+        // a run of zero low coefficients is a root at zero
+        let zeros = self.coeffs.iter().take_while(|c| !c.not_zero()).count();
+        if zeros == self.coeffs.len() {
+            return Err(PolysolveError::ZeroPolynomial);
+        }
+        if zeros > 0 {
+            roots.insert(BigNumber::zero());
+        }
+
+        let lcm = self.coeffs[zeros..]
+            .iter()
+            .fold(BigInt::one(), |acc, c| acc.lcm(c.denom()));
+        let integers: Vec<BigInt> = self.coeffs[zeros..]
+            .iter()
+            .map(|c| c.numer() * (&lcm / c.denom()))
+            .collect();
+
+        let constant_factors = divisors(&integers[0]);
+        let leading_factors = divisors(&integers[integers.len() - 1]);
+        for p in &constant_factors {
+            for q in &leading_factors {
+                for candidate in [
+                    BigNumber::new(p.clone(), q.clone()),
+                    BigNumber::new(-p, q.clone()),
+                ] {
+                    if !self.evaluate_exact(&candidate).not_zero() {
+                        roots.insert(candidate);
+                    }
+                }
+            }
+        }
+
+        Ok(roots.into_iter().collect())
+    }
+}
+
+// positive divisors of |n| by trial division up to its square root
+fn divisors(n: &BigInt) -> Vec<BigInt> {
+    let n = n.abs();
+    let mut small = Vec::new();
+    let mut large = Vec::new();
+    let mut d = BigInt::one();
+    while &d * &d <= n {
+        if n.is_multiple_of(&d) {
+            let pair = &n / &d;
+            if pair != d {
+                large.push(pair);
+            }
+            small.push(d.clone());
+        }
+        d += 1;
+    }
+    small.extend(large.into_iter().rev());
+    small
+}
+
+impl From<&PolynomialFunction> for BigPolynomialFunction {
+    fn from(value: &PolynomialFunction) -> Self {
+        let coeffs: Vec<BigNumber> = value.coefficients().into_iter().map(Into::into).collect();
+        BigPolynomialFunction::from_coeffs(&coeffs)
+    }
+}
+
+impl Add for BigPolynomialFunction {
+    type Output = BigPolynomialFunction;
+
+    fn add(self, rhs: BigPolynomialFunction) -> Self::Output {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        let coeffs: Vec<BigNumber> = (0..len as i32)
+            .map(|d| self.coefficient(d) + rhs.coefficient(d))
+            .collect();
+        BigPolynomialFunction::from_coeffs(&coeffs)
+    }
+}
+
+impl Neg for BigPolynomialFunction {
+    type Output = BigPolynomialFunction;
+
+    fn neg(self) -> Self::Output {
+        BigPolynomialFunction {
+            coeffs: self.coeffs.into_iter().map(Neg::neg).collect(),
+        }
+    }
+}
+
+impl Mul for BigPolynomialFunction {
+    type Output = BigPolynomialFunction;
+
+    fn mul(self, rhs: BigPolynomialFunction) -> Self::Output {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return BigPolynomialFunction { coeffs: Vec::new() };
+        }
+        let mut coeffs = vec![BigNumber::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        BigPolynomialFunction::from_coeffs(&coeffs)
+    }
+}
+
+impl fmt::Display for BigPolynomialFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.coeffs.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut first = true;
+        for (degree, c) in self.coeffs.iter().enumerate().rev() {
+            if !c.not_zero() {
+                continue;
+            }
+            match (first, c.signum() < 0) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+
+            let coefficient = c.abs();
+            if degree == 0 {
+                write!(f, "{}", coefficient)?;
+                continue;
+            }
+            if coefficient != BigNumber::one() {
+                write!(f, "{}", coefficient)?;
+                if !coefficient.is_integer() {
+                    write!(f, " ")?;
+                }
+            }
+            match degree {
+                1 => write!(f, "x")?,
+                d => write!(f, "x^{}", d)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn big_number_arithmetic() {
+        let a = BigNumber::new(i64::MAX, 3);
+        let b = BigNumber::new(-5, 7);
+        assert_eq!((a.clone() * b.clone()) / b.clone(), a);
+        assert_eq!(a.clone() - a.clone(), BigNumber::zero());
+        assert_eq!(BigNumber::new(6, -8), BigNumber::new(-3, 4));
+        assert_eq!(BigNumber::new(6, -8).to_string(), "-3/4");
+        assert!(b < BigNumber::zero() && BigNumber::zero() < a);
+        assert_eq!(BigNumber::try_new(1, 0), Err(NumberError::ZeroDenominator));
+
+        let squared = a.clone() * a;
+        assert!(squared.to_number().is_none());
+        assert_eq!(
+            BigNumber::from(Number::new(3, 4, false)).to_number(),
+            Some(Number::new(3, 4, false))
+        );
+    }
+
+    #[test]
+    fn big_backing_matches_i64() {
+        fn alternating<I: crate::types::Backing>(n: i32) -> Rational<I> {
+            (1..=n).fold(Rational::zero(), |acc, k| {
+                acc + Rational::from((if k % 2 == 0 { -1 } else { 1 }, k))
+            })
+        }
+
+        let small = alternating::<i64>(9);
+        assert_eq!(alternating::<BigInt>(9), BigNumber::from(small));
+
+        // products past i64 only fit the bignum backing
+        let big = BigNumber::from(i64::MAX).pow(3);
+        assert_eq!(big.to_string(), (BigInt::from(i64::MAX).pow(3)).to_string());
+        assert_eq!(
+            big.clone() / BigNumber::from(i64::MAX).pow(2),
+            BigNumber::from(i64::MAX)
+        );
+
+        // past Number's range, powers stay exact
+        assert_eq!(
+            BigNumber::new(2, 3).pow(40),
+            BigNumber::new(BigInt::from(2).pow(40), BigInt::from(3).pow(40))
+        );
+    }
+
+    #[test]
+    fn multiply_past_u32() {
+        // (x^2 + 100000x + 1)(x^2 + 70000x + 3)
+        let a = BigPolynomialFunction::from_coeffs(&[1.into(), 100000.into(), 1.into()]);
+        let b = BigPolynomialFunction::from_coeffs(&[3.into(), 70000.into(), 1.into()]);
+        let product = a * b;
+        assert_eq!(
+            product.coefficients(),
+            &[
+                3.into(),
+                370000.into(),
+                BigNumber::from(7_000_000_004_i64),
+                170000.into(),
+                1.into()
+            ]
+        );
+        assert!(product.coefficient(2) > BigNumber::from(u32::MAX as i64));
+        assert_eq!(
+            product.to_string(),
+            "x^4 + 170000x^3 + 7000000004x^2 + 370000x + 3"
+        );
+    }
+
+    #[test]
+    fn big_roots() {
+        let expected = vec![
+            BigNumber::from(-98765),
+            BigNumber::zero(),
+            BigNumber::new(2, 3),
+            BigNumber::from(123456),
+        ];
+        let func = BigPolynomialFunction::from_roots(&expected);
+        assert!(func
+            .coefficients()
+            .iter()
+            .any(|c| c.abs() > BigNumber::from(u32::MAX as i64)));
+        assert_eq!(func.roots(), Ok(expected));
+
+        let from_small: BigPolynomialFunction =
+            (&"2x^2 - 8".parse::<PolynomialFunction>().unwrap()).into();
+        assert_eq!(
+            from_small.roots(),
+            Ok(vec![BigNumber::from(-2), BigNumber::from(2)])
+        );
+    }
+
+    #[test]
+    fn big_roots_zero_polynomial() {
+        // agrees with the i64-backed polynomial
+        let zero = PolynomialFunction::zero();
+        assert_eq!(zero.roots(), Err(PolysolveError::ZeroPolynomial));
+        assert_eq!(
+            BigPolynomialFunction::from(&zero).roots(),
+            Err(PolysolveError::ZeroPolynomial)
+        );
+        assert_eq!(
+            BigPolynomialFunction::from_coeffs(&[BigNumber::zero()]).roots(),
+            Err(PolysolveError::ZeroPolynomial)
+        );
+    }
+
+    #[test]
+    fn big_to_f64_out_of_range() {
+        let huge = BigInt::from(10).pow(400);
+        assert_eq!(crate::types::Backing::to_f64(&huge), f64::INFINITY);
+        assert_eq!(crate::types::Backing::to_f64(&-huge), f64::NEG_INFINITY);
+        assert_eq!(BigNumber::new(-3, 4).to_f64(), -0.75);
+    }
+}
@@ -0,0 +1,100 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn magnitude(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn conjugate(&self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl Add<Complex> for Complex {
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Self::Output {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub<Complex> for Complex {
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Self::Output {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul<Complex> for Complex {
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Self::Output {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div<Complex> for Complex {
+    type Output = Complex;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Complex) -> Self::Output {
+        let numerator = self * rhs.conjugate();
+        let denominator = rhs.re * rhs.re + rhs.im * rhs.im;
+        Complex::new(numerator.re / denominator, numerator.im / denominator)
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(value: f64) -> Self {
+        Self::new(value, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_i_squared() {
+        let i = Complex::new(0.0, 1.0);
+        assert_eq!(i * i, Complex::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+
+        let c = Complex::new(1.0, 1.0);
+        assert_eq!(a / c, Complex::new(1.5, 0.5));
+        assert_eq!((a / c) * c, a);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_magnitude_and_conjugate() {
+        let a = Complex::new(3.0, 4.0);
+
+        assert_eq!(a.magnitude(), 5.0);
+        assert_eq!(a.conjugate(), Complex::new(3.0, -4.0));
+        assert_eq!(a * a.conjugate(), Complex::new(25.0, 0.0));
+    }
+}
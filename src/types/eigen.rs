@@ -0,0 +1,171 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::Complex;
+
+// Eigenvalues of a real upper Hessenberg matrix by shifted QR iteration
+// (the EISPACK `hqr` scheme). The matrix is destroyed in the process. `None` if
+// some eigenvalue fails to converge within 60 iterations.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn hessenberg_eigenvalues(mut a: Vec<Vec<f64>>) -> Option<Vec<Complex>> {
+    let n = a.len();
+    let mut out = vec![Complex::new(0.0, 0.0); n];
+
+    let mut norm = 0.0;
+    for (i, row) in a.iter().enumerate() {
+        for value in &row[i.saturating_sub(1)..] {
+            norm += value.abs();
+        }
+    }
+
+    let mut nn = n as isize - 1;
+    let mut t = 0.0;
+
+    while nn >= 0 {
+        let mut its = 0;
+        loop {
+            let hi = nn as usize;
+
+            let mut l = hi;
+            while l > 0 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0.0 {
+                    s = norm;
+                }
+                if a[l][l - 1].abs() <= f64::EPSILON * s {
+                    a[l][l - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+
+            let mut x = a[hi][hi];
+            if l == hi {
+                out[hi] = Complex::new(x + t, 0.0);
+                nn -= 1;
+                break;
+            }
+
+            let mut y = a[hi - 1][hi - 1];
+            let mut w = a[hi][hi - 1] * a[hi - 1][hi];
+            if l == hi - 1 {
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += t;
+                if q >= 0.0 {
+                    let z = p + z.copysign(p);
+                    out[hi - 1] = Complex::new(x + z, 0.0);
+                    out[hi] = Complex::new(if z != 0.0 { x - w / z } else { x + z }, 0.0);
+                } else {
+                    out[hi] = Complex::new(x + p, -z);
+                    out[hi - 1] = Complex::new(x + p, z);
+                }
+                nn -= 2;
+                break;
+            }
+
+            if its == 60 {
+                return None;
+            }
+            if its == 10 || its == 20 {
+                // exceptional shift
+                t += x;
+                for (i, row) in a.iter_mut().enumerate().take(hi + 1) {
+                    row[i] -= x;
+                }
+                let s = a[hi][hi - 1].abs() + a[hi - 1][hi - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            its += 1;
+
+            let (mut p, mut q, mut r): (f64, f64, f64);
+            let mut m = hi - 2;
+            loop {
+                let z = a[m][m];
+                let rr = x - z;
+                let ss = y - z;
+                p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - rr - ss;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u <= f64::EPSILON * v {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m..hi - 1 {
+                a[i + 2][i] = 0.0;
+                if i != m {
+                    a[i + 2][i - 1] = 0.0;
+                }
+            }
+
+            for k in m..hi {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k + 1 != hi { a[k + 2][k - 1] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0.0 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+
+                let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == 0.0 {
+                    continue;
+                }
+
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+
+                for j in k..=hi {
+                    let mut p = a[k][j] + q * a[k + 1][j];
+                    if k + 1 != hi {
+                        p += r * a[k + 2][j];
+                        a[k + 2][j] -= p * z;
+                    }
+                    a[k + 1][j] -= p * y;
+                    a[k][j] -= p * x;
+                }
+
+                for row in a.iter_mut().take(hi.min(k + 3) + 1).skip(l) {
+                    let mut p = x * row[k] + y * row[k + 1];
+                    if k + 1 != hi {
+                        p += z * row[k + 2];
+                        row[k + 2] -= p * r;
+                    }
+                    row[k + 1] -= p * q;
+                    row[k] -= p;
+                }
+            }
+        }
+    }
+
+    Some(out)
+}
@@ -1,237 +1,3424 @@
+#[cfg(feature = "bigint")]
+mod big;
+mod complex;
+#[cfg(feature = "std")]
+mod eigen;
 mod number;
+mod parse;
+mod rational_function;
+#[cfg(feature = "serde")]
+mod serialize;
 
-use std::collections::{HashMap, HashSet};
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
 
-use self::number::Number;
+use crate::error::PolysolveError;
 
-#[derive(Debug, PartialEq, Eq)]
-struct Term {
+#[cfg(feature = "bigint")]
+pub use self::big::{BigNumber, BigPolynomialFunction};
+pub use self::complex::Complex;
+pub use self::number::{Backing, Number, NumberError, Rational};
+pub use self::rational_function::RationalFunction;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Term {
     coefficient: Number,
     degree: i32,
 }
 
 impl Term {
-    fn new(coefficient: Number, degree: i32) -> Self {
+    /// Panics if `degree` is negative.
+    pub fn new(coefficient: Number, degree: i32) -> Self {
+        assert!(degree >= 0, "negative degree {}", degree);
         Self {
             coefficient,
             degree,
         }
     }
+}
 
-    fn evaluate(&self, x: Number) -> Number {
-        let t = x.pow(self.degree);
-        let out = t * self.coefficient;
-        out
+/// Whether `a` and `b` are within `tol` of each other.
+pub fn approx_eq(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol
+}
+
+/// Collects terms one at a time, simplifying once in [`PolynomialBuilder::build`].
+#[derive(Debug, Clone, Default)]
+pub struct PolynomialBuilder {
+    terms: Vec<Term>,
+}
+
+impl PolynomialBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn term(mut self, coefficient: Number, degree: i32) -> Self {
+        self.terms.push(Term::new(coefficient, degree));
+        self
+    }
+
+    pub fn build(self) -> PolynomialFunction {
+        PolynomialFunction::new(self.terms)
     }
 }
 
-struct PolynomialFunction {
+// terms are kept simplified and sorted, so structural equality is polynomial equality
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolynomialFunction {
     terms: Vec<Term>,
 }
 
 impl PolynomialFunction {
-    fn new(terms: Vec<Term>) -> Self {
+    pub fn new(terms: Vec<Term>) -> Self {
         let mut out = Self { terms };
         out.simplify();
         out
     }
 
+    /// The zero polynomial, with no terms.
+    pub fn zero() -> Self {
+        Self { terms: Vec::new() }
+    }
+
+    /// The constant `value`; zero gives [`PolynomialFunction::zero`].
+    pub fn constant(value: Number) -> Self {
+        Self::new(vec![Term::new(value, 0)])
+    }
+
+    pub fn builder() -> PolynomialBuilder {
+        PolynomialBuilder::new()
+    }
+
     fn simplify(&mut self) {
-        let mut map: HashMap<i32, Term> = HashMap::new();
-
-        for mut t in std::mem::take(&mut self.terms).into_iter() {
-            match map.get_mut(&t.degree) {
-                Some(original_term) => original_term.coefficient += t.coefficient,
-                None => {
-                    t.coefficient.simplify();
-                    map.insert(t.degree, t);
-                }
+        let mut terms = core::mem::take(&mut self.terms);
+        terms.sort_by_key(|t| core::cmp::Reverse(t.degree));
+
+        for t in terms {
+            match self.terms.last_mut() {
+                Some(last) if last.degree == t.degree => last.coefficient += t.coefficient,
+                _ => self.terms.push(t),
             }
         }
 
-        self.terms = map
-            .into_iter()
-            .map(|(_, t)| t)
-            .filter(|t| t.coefficient.not_zero())
-            .collect();
+        self.terms.retain(|t| t.coefficient.not_zero());
+    }
+
+    /// Builds from dense coefficients where `coeffs[i]` is the coefficient of `x^i`.
+    pub fn from_coeffs(coeffs: &[Number]) -> PolynomialFunction {
+        PolynomialFunction::new(
+            coeffs
+                .iter()
+                .enumerate()
+                .map(|(i, c)| Term::new(*c, i as i32))
+                .collect(),
+        )
+    }
 
-        self.terms.sort_by(|x, y| y.degree.cmp(&x.degree));
+    /// `(degree, coefficient)` pairs of the nonzero terms, highest degree first.
+    pub fn iter_terms(&self) -> impl Iterator<Item = (i32, Number)> + '_ {
+        self.terms.iter().map(|t| (t.degree, t.coefficient))
     }
 
-    // Change x to Number?
-    pub fn evaluate(&self, x: f64) -> f64 {
+    /// The coefficient of `x^degree`, zero if there is no such term.
+    pub fn coefficient(&self, degree: i32) -> Number {
         self.terms
+            .binary_search_by_key(&core::cmp::Reverse(degree), |t| {
+                core::cmp::Reverse(t.degree)
+            })
+            .map_or(Number::zero(), |i| self.terms[i].coefficient)
+    }
+
+    /// Dense coefficients where index `i` holds the coefficient of `x^i`.
+    pub fn coefficients(&self) -> Vec<Number> {
+        let mut out = vec![Number::zero(); self.degree().map_or(0, |d| d as usize + 1)];
+        for t in &self.terms {
+            out[t.degree as usize] = t.coefficient;
+        }
+        out
+    }
+
+    /// The monic polynomial `(x - r1)(x - r2)...` with exactly the given roots.
+    pub fn from_roots(roots: &[Number]) -> PolynomialFunction {
+        roots
             .iter()
-            .fold(0 as f64, |acc, t| (t.evaluate(x.into())) + acc)
+            .fold(PolynomialFunction::constant(Number::one()), |acc, r| {
+                acc * PolynomialFunction::new(vec![Term::new(1.into(), 1), Term::new(-*r, 0)])
+            })
     }
 
-    fn _evaluate(&self, x: Number) -> Number {
-        self.terms
+    /// The lowest degree polynomial through `points`, by Lagrange interpolation.
+    ///
+    /// Panics if two points share an x-value.
+    pub fn interpolate(points: &[(Number, Number)]) -> PolynomialFunction {
+        let mut out = PolynomialFunction::zero();
+
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let others: Vec<Number> = points
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, (xj, _))| *xj)
+                .collect();
+
+            let mut denominator = Number::one();
+            for xj in &others {
+                let difference = *xi - *xj;
+                assert!(
+                    difference.not_zero(),
+                    "cannot interpolate through two points with x = {}",
+                    xi
+                );
+                denominator *= difference;
+            }
+
+            out = out + PolynomialFunction::from_roots(&others).scale(*yi / denominator);
+        }
+
+        out
+    }
+
+    /// Like [`PolynomialFunction::interpolate`], but `None` if two points share an x-value.
+    pub fn interpolate_lagrange(points: &[(Number, Number)]) -> Option<PolynomialFunction> {
+        let distinct = points
             .iter()
-            .fold(0.into(), |acc, t| t.evaluate(x) + acc)
+            .enumerate()
+            .all(|(i, (xi, _))| points[..i].iter().all(|(xj, _)| xj != xi));
+        distinct.then(|| PolynomialFunction::interpolate(points))
     }
 
-    fn roots(&self) -> Vec<Number> {
-        let mut roots = HashSet::new();
+    /// The degree-`degree` polynomial minimising the squared error over `points`,
+    /// from the normal equations. `None` with fewer points than coefficients, when
+    /// the x-values are too few to pin the fit down, or when a fitted coefficient is
+    /// not finite or does not fit a [`Number`].
+    #[cfg(feature = "std")]
+    pub fn fit_least_squares(points: &[(f64, f64)], degree: usize) -> Option<PolynomialFunction> {
+        let n = degree + 1;
+        if points.len() < n {
+            return None;
+        }
 
-        let constant_term = self
-            .terms
-            .last()
-            .map(|x| {
-                if x.degree == 0 {
-                    x.coefficient
-                } else {
-                    1.into()
+        // power sums of x up to 2 * degree fill the Gram matrix
+        let mut power_sums = vec![0_f64; 2 * degree + 1];
+        let mut rhs = vec![0_f64; n];
+        for &(x, y) in points {
+            let mut power = 1.0;
+            for (k, sum) in power_sums.iter_mut().enumerate() {
+                *sum += power;
+                if k < n {
+                    rhs[k] += power * y;
                 }
-            })
-            .unwrap_or(1.into());
+                power *= x;
+            }
+        }
+        let gram = (0..n).map(|i| power_sums[i..i + n].to_vec()).collect();
+
+        let coeffs: Vec<Number> = solve_linear_system(gram, rhs)?
+            .into_iter()
+            .map(|c| Number::from_f64_approx(c, 1_000_000).ok())
+            .collect::<Option<_>>()?;
+        Some(PolynomialFunction::from_coeffs(&coeffs))
+    }
+
+    pub fn degree(&self) -> Option<i32> {
+        self.terms.first().map(|t| t.degree)
+    }
 
-        let leading_coefficient = self
+    /// Zero for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Number {
+        self.terms.first().map_or(Number::zero(), |t| t.coefficient)
+    }
+
+    /// Divides through by the leading coefficient; `None` for the zero polynomial.
+    pub fn to_monic(&self) -> Option<PolynomialFunction> {
+        let leading = self.terms.first()?.coefficient;
+        Some(PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(t.coefficient / leading, t.degree))
+                .collect(),
+        ))
+    }
+
+    /// The rational gcd of the coefficients, signed like the leading coefficient, so
+    /// `self / content` has coprime integer coefficients and a positive leading term.
+    /// Zero for the zero polynomial.
+    pub fn content(&self) -> Number {
+        let gcd = self
             .terms
-            .first()
-            .map(|x| {
-                if x.degree == 0 {
-                    x.coefficient
-                } else {
-                    1.into()
-                }
-            })
-            .unwrap_or(1.into());
-        let mut divisor = 1;
-        for num in self.terms.iter().map(|x| x.coefficient) {
-            if !num.is_integer() {
-                divisor *= num.denominator;
-            }
+            .iter()
+            .fold(Number::zero(), |acc, t| acc.gcd(&t.coefficient));
+        if self.leading_coefficient().is_negative() {
+            -gcd
+        } else {
+            gcd
         }
+    }
 
-        let lc_factors = (leading_coefficient * divisor).factors();
+    pub fn primitive_part(&self) -> PolynomialFunction {
+        let content = self.content();
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(t.coefficient / content, t.degree))
+                .collect(),
+        )
+    }
 
-        let potential_roots = (constant_term * divisor)
-            .factors()
-            .into_iter()
-            .flat_map(|c| {
-                lc_factors.iter().map(move |l| {
-                    Number::new(
-                        c.abs() as u32,
-                        l.abs() as u32,
-                        c.is_positive() == l.is_positive(),
-                    )
-                })
-            });
+    /// The sum of all complex roots counted with multiplicity, `-a_{n-1} / a_n`.
+    pub fn sum_of_roots(&self) -> Option<Number> {
+        let degree = self.degree().filter(|d| *d > 0)?;
+        Some(-(self.coefficient(degree - 1) / self.leading_coefficient()))
+    }
 
-        for x in potential_roots {
-            if self._evaluate(x).numerator == 0 {
-                roots.insert(x);
+    /// The product of all complex roots counted with multiplicity, `(-1)^n a_0 / a_n`.
+    pub fn product_of_roots(&self) -> Option<Number> {
+        let degree = self.degree().filter(|d| *d > 0)?;
+        let product = self.coefficient(0) / self.leading_coefficient();
+        Some(if degree % 2 == 0 { product } else { -product })
+    }
+
+    /// The discriminant of a quadratic or cubic; `None` for any other degree.
+    pub fn discriminant(&self) -> Option<Number> {
+        match self.degree()? {
+            2 => {
+                let (a, b, c) = (
+                    self.coefficient(2),
+                    self.coefficient(1),
+                    self.coefficient(0),
+                );
+                Some(b * b - Number::from(4) * a * c)
+            }
+            3 => {
+                let (a, b, c, d) = (
+                    self.coefficient(3),
+                    self.coefficient(2),
+                    self.coefficient(1),
+                    self.coefficient(0),
+                );
+                Some(
+                    Number::from(18) * a * b * c * d - Number::from(4) * b * b * b * d
+                        + b * b * c * c
+                        - Number::from(4) * a * c * c * c
+                        - Number::from(27) * a * a * d * d,
+                )
             }
+            _ => None,
         }
+    }
 
-        roots.into_iter().collect()
+    #[cfg(feature = "std")]
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.terms.iter().fold(0_f64, |acc, t| {
+            acc + t.coefficient.to_f64() * x.powi(t.degree)
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn poly_new() {
-        assert_eq!(PolynomialFunction::new(Vec::new()).terms.len(), 0);
+    /// Evaluates at every point of `xs`, converting the coefficients once and
+    /// running Horner's scheme per point.
+    pub fn evaluate_many(&self, xs: &[f64]) -> Vec<f64> {
+        let terms: Vec<(i32, f64)> = self
+            .terms
+            .iter()
+            .map(|t| (t.degree, t.coefficient.to_f64()))
+            .collect();
+        let degree = self.degree().unwrap_or(0);
+        xs.iter()
+            .map(|&x| {
+                let (value, lowest) =
+                    terms.iter().fold((0_f64, degree), |(acc, above), &(d, c)| {
+                        (acc * float_pow(x, above - d) + c, d)
+                    });
+                value * float_pow(x, lowest)
+            })
+            .collect()
     }
 
-    #[test]
-    fn simplify() {
-        let terms = vec![
-            Term::new(Number::new(5, 1, true), 0),
-            Term::new(Number::new(10, 1, true), 0),
-            Term::new(Number::new(4, 2, true), 1),
-            Term::new(Number::new(4, 1, true), 1),
-            Term::new(Number::new(4, 2, true), 2),
-            Term::new(Number::new(4, 2, true), 3),
-            Term::new(Number::new(4, 2, false), 3),
-        ];
+    /// `(f(x), f'(x))` from a single Horner pass.
+    pub fn eval_with_derivative(&self, x: f64) -> (f64, f64) {
+        // across a gap of k missing terms, (v, s) becomes (v x^k, s x^k + k v x^(k-1))
+        let step = |(value, slope): (f64, f64), k: i32| {
+            if k == 0 {
+                return (value, slope);
+            }
+            let power = float_pow(x, k - 1);
+            (
+                value * power * x,
+                slope * power * x + k as f64 * value * power,
+            )
+        };
+        let ((value, slope), lowest) = self.terms.iter().fold(
+            ((0_f64, 0_f64), self.degree().unwrap_or(0)),
+            |(acc, above), t| {
+                let (value, slope) = step(acc, above - t.degree);
+                ((value + t.coefficient.to_f64(), slope), t.degree)
+            },
+        );
+        step((value, slope), lowest)
+    }
 
-        let func = PolynomialFunction::new(terms);
+    /// Deprecated name of [`PolynomialFunction::eval_with_derivative`], which it
+    /// forwards to.
+    #[deprecated(note = "use `eval_with_derivative`")]
+    pub fn eval_and_deriv(&self, x: f64) -> (f64, f64) {
+        self.eval_with_derivative(x)
+    }
 
-        assert_eq!(
-            func.terms,
-            vec![
-                Term::new(Number::new(2, 1, true), 2),
-                Term::new(Number::new(6, 1, true), 1),
-                Term::new(Number::new(15, 1, true), 0),
-            ]
+    /// Exact evaluation by Horner's scheme over the terms, bridging each gap in
+    /// degree with one `pow` by repeated squaring, so a sparse polynomial costs
+    /// its number of terms rather than its degree.
+    pub fn evaluate_exact(&self, x: Number) -> Number {
+        let (value, lowest) = self.terms.iter().fold(
+            (Number::zero(), self.degree().unwrap_or(0)),
+            |(acc, above), t| (acc * x.pow(above - t.degree) + t.coefficient, t.degree),
         );
+        value * x.pow(lowest)
     }
 
-    #[test]
-    fn evaluate() {
-        let terms = vec![
-            Term::new(Number::new(15, 1, true), 0),
-            Term::new(Number::new(6, 1, true), 1),
-            Term::new(Number::new(2, 1, true), 2),
-        ];
+    /// Exact evaluation at anything convertible to a `Number`.
+    pub fn evaluate_at<T: Into<Number>>(&self, x: T) -> Number {
+        self.evaluate_exact(x.into())
+    }
 
-        let func = PolynomialFunction::new(terms);
+    pub fn derivative(&self) -> PolynomialFunction {
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .filter(|t| t.degree != 0)
+                .map(|t| Term::new(t.coefficient * t.degree as u32, t.degree - 1))
+                .collect(),
+        )
+    }
 
-        assert_eq!(func.evaluate(15 as f64), 555 as f64);
+    /// The antiderivative with a zero constant term.
+    pub fn integral(&self) -> PolynomialFunction {
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(t.coefficient / (t.degree + 1) as u32, t.degree + 1))
+                .collect(),
+        )
     }
 
-    #[test]
-    fn find_roots() {
-        let terms = vec![
-            Term::new(Number::new(1, 1, true), 2),
-            Term::new(Number::new(5, 1, false), 1),
-            Term::new(Number::new(6, 1, true), 0),
-        ];
+    /// The exact definite integral from `a` to `b`.
+    pub fn integrate(&self, a: Number, b: Number) -> Number {
+        let antiderivative = self.integral();
+        antiderivative.evaluate_exact(b) - antiderivative.evaluate_exact(a)
+    }
 
-        let func = PolynomialFunction::new(terms);
-        let mut roots = func.roots();
-        roots.sort();
+    /// `self` multiplied by itself `exp` times, by repeated squaring.
+    pub fn pow(&self, exp: u32) -> PolynomialFunction {
+        let mut result = PolynomialFunction::constant(Number::one());
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
 
-        assert_eq!(
-            roots,
-            vec![Number::new(2, 1, true), Number::new(3, 1, true)]
+    /// `self(g(x))`, by Horner's scheme with `g` in place of `x`.
+    pub fn compose(&self, g: &PolynomialFunction) -> PolynomialFunction {
+        let degree = match self.degree() {
+            Some(degree) => degree,
+            None => return PolynomialFunction::zero(),
+        };
+
+        let mut out = PolynomialFunction::zero();
+        let mut terms = self.terms.iter().peekable();
+
+        for d in (0..=degree).rev() {
+            out = &out * g;
+            if let Some(t) = terms.next_if(|t| t.degree == d) {
+                out = out + PolynomialFunction::constant(t.coefficient);
+            }
+        }
+
+        out
+    }
+
+    /// `f(x + a)`, expanded. Repeated synthetic division by `(x - a)` yields the
+    /// coefficients of `f` in powers of `(x - a)`, which are exactly those of `f(x + a)`.
+    pub fn shift(&self, a: Number) -> PolynomialFunction {
+        let mut coefficients = Vec::new();
+        let (mut quotient, remainder) = self.synthetic_divide(a);
+        coefficients.push(remainder);
+
+        while !quotient.terms.is_empty() {
+            let (next, remainder) = quotient.synthetic_divide(a);
+            coefficients.push(remainder);
+            quotient = next;
+        }
+
+        PolynomialFunction::from_coeffs(&coefficients)
+    }
+
+    /// The Taylor expansion about `a`: coefficient `k` of the result is
+    /// `f^(k)(a) / k!`, the coefficient of `(x - a)^k`. The same polynomial as
+    /// [`PolynomialFunction::shift`], built from derivatives instead.
+    pub fn taylor_at(&self, a: Number) -> PolynomialFunction {
+        PolynomialFunction::from_coeffs(&self.taylor_coefficients(a))
+    }
+
+    /// The coefficients of [`PolynomialFunction::taylor_at`], `f^(k)(a) / k!` at
+    /// index `k`, one per degree up to the polynomial's own.
+    pub fn taylor_coefficients(&self, a: Number) -> Vec<Number> {
+        let mut coefficients = Vec::new();
+        let mut derivative = self.clone();
+        let mut factorial = Number::one();
+        let mut k = 0;
+        while !derivative.terms.is_empty() {
+            coefficients.push(derivative.evaluate_exact(a) / factorial);
+            derivative = derivative.derivative();
+            k += 1;
+            factorial *= Number::from(k);
+        }
+        coefficients
+    }
+
+    /// Every coefficient multiplied by `c`.
+    pub fn scale(&self, c: Number) -> PolynomialFunction {
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(t.coefficient * c, t.degree))
+                .collect(),
         )
     }
 
-    #[test]
-    fn find_roots_fractional() {
-        let terms = vec![
-            Term::new(Number::new(1, 1, true), 3),
-            Term::new(Number::new(9, 2, false), 2),
-            Term::new(Number::new(7, 2, true), 1),
-            Term::new(Number::new(3, 1, true), 0),
-        ];
+    /// Applies `f` to every coefficient, dropping any that become zero.
+    pub fn map_coefficients<F: FnMut(Number) -> Number>(&self, mut f: F) -> PolynomialFunction {
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(f(t.coefficient), t.degree))
+                .collect(),
+        )
+    }
 
-        let func = PolynomialFunction::new(terms);
-        let mut roots = func.roots();
-        roots.sort();
-        assert_eq!(
-            roots,
-            vec![
-                Number::new(1, 2, false),
-                Number::new(2, 1, true),
-                Number::new(3, 1, true)
-            ]
+    /// Each coefficient reduced into `0..p`, dropping those divisible by `p`.
+    /// Fails with [`PolysolveError::NotInteger`] on a non-integer coefficient, and
+    /// with [`PolysolveError::DivisionByZero`] if `p` is zero.
+    pub fn modulo(&self, p: u32) -> Result<PolynomialFunction, PolysolveError> {
+        if p == 0 {
+            return Err(PolysolveError::DivisionByZero);
+        }
+        let terms = self
+            .terms
+            .iter()
+            .map(|t| {
+                if !t.coefficient.is_integer() {
+                    return Err(PolysolveError::NotInteger);
+                }
+                let reduced = t.coefficient.numer().rem_euclid(p as i64);
+                Ok(Term::new(Number::ratio(reduced, 1), t.degree))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(PolynomialFunction::new(terms))
+    }
+
+    /// `f(c * x)`, expanded.
+    pub fn scale_variable(&self, c: Number) -> PolynomialFunction {
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(t.coefficient * c.pow(t.degree), t.degree))
+                .collect(),
         )
     }
 
-    #[test]
-    fn find_roots_irrational() {
-        let terms = vec![
-            Term::new(Number::new(1, 3, true), 3),
-            Term::new(Number::new(1, 9, true), 2),
-            Term::new(Number::new(1, 2, false), 1),
-            Term::new(Number::new(4, 7, true), 0),
-        ];
-        let func = PolynomialFunction::new(terms);
-        let mut roots = func.roots();
-        roots.sort();
-        assert_eq!(
-            roots,
-            vec![
-                Number::new(1, 2, false),
-                Number::new(2, 1, true),
-                Number::new(3, 1, true)
-            ]
+    /// `x^n * f(1/x)` for `f` of degree `n`: the coefficients in reverse order, with
+    /// the reciprocals of the nonzero roots as roots. A zero constant term drops
+    /// the degree, so reversing twice strips any factor of `x`.
+    pub fn reverse(&self) -> PolynomialFunction {
+        let degree = self.degree().unwrap_or(0);
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(t.coefficient, degree - t.degree))
+                .collect(),
         )
     }
+
+    /// `a_i == a_(n-i)` for every `i`; such polynomials equal their own `reverse`.
+    pub fn is_palindromic(&self) -> bool {
+        let coeffs = self.coefficients();
+        coeffs.iter().zip(coeffs.iter().rev()).all(|(a, b)| a == b)
+    }
+
+    /// `a_i == -a_(n-i)` for every `i`.
+    pub fn is_anti_palindromic(&self) -> bool {
+        let coeffs = self.coefficients();
+        coeffs
+            .iter()
+            .zip(coeffs.iter().rev())
+            .all(|(&a, &b)| a == -b)
+    }
+
+    /// Polynomial long division, returning `(quotient, remainder)`.
+    pub fn div_rem(
+        &self,
+        divisor: &PolynomialFunction,
+    ) -> Result<(PolynomialFunction, PolynomialFunction), PolysolveError> {
+        if divisor.terms.is_empty() {
+            return Err(PolysolveError::DivisionByZero);
+        }
+        Ok(self.long_division(divisor))
+    }
+
+    // div_rem for a divisor already known to be nonzero
+    fn long_division(
+        &self,
+        divisor: &PolynomialFunction,
+    ) -> (PolynomialFunction, PolynomialFunction) {
+        self.checked_long_division(divisor)
+            .expect("Number overflow")
+    }
+
+    // long_division, failing instead of panicking on overflow
+    fn checked_long_division(
+        &self,
+        divisor: &PolynomialFunction,
+    ) -> Result<(PolynomialFunction, PolynomialFunction), PolysolveError> {
+        let divisor_degree = divisor.degree().unwrap_or(0);
+        let leading = divisor.leading_coefficient();
+
+        let mut remainder = self.coefficients();
+        let mut quotient = Vec::new();
+
+        for d in (divisor_degree as usize..remainder.len()).rev() {
+            if !remainder[d].not_zero() {
+                continue;
+            }
+            let coefficient = remainder[d].checked_div(leading)?;
+            let shift = d as i32 - divisor_degree;
+            for t in &divisor.terms {
+                let i = (t.degree + shift) as usize;
+                remainder[i] = remainder[i].checked_sub(coefficient.checked_mul(t.coefficient)?)?;
+            }
+            quotient.push(Term::new(coefficient, shift));
+        }
+
+        Ok((
+            PolynomialFunction::new(quotient),
+            PolynomialFunction::from_coeffs(&remainder),
+        ))
+    }
+
+    /// The monic greatest common divisor, by the Euclidean algorithm.
+    pub fn gcd(&self, other: &PolynomialFunction) -> PolynomialFunction {
+        self.checked_gcd(other).expect("Number overflow")
+    }
+
+    fn checked_gcd(
+        &self,
+        other: &PolynomialFunction,
+    ) -> Result<PolynomialFunction, PolysolveError> {
+        if other.terms.is_empty() {
+            let leading = self.leading_coefficient();
+            let terms = self
+                .terms
+                .iter()
+                .map(|t| Ok(Term::new(t.coefficient.checked_div(leading)?, t.degree)))
+                .collect::<Result<_, PolysolveError>>()?;
+            return Ok(PolynomialFunction::new(terms));
+        }
+        let (_, remainder) = self.checked_long_division(other)?;
+        other.checked_gcd(&remainder)
+    }
+
+    /// `f / gcd(f, f')`: each distinct root of `f` once, with the same leading coefficient.
+    pub fn square_free_part(&self) -> PolynomialFunction {
+        self.checked_square_free_part().expect("Number overflow")
+    }
+
+    fn checked_square_free_part(&self) -> Result<PolynomialFunction, PolysolveError> {
+        if self.terms.is_empty() {
+            return Ok(PolynomialFunction::zero());
+        }
+        let derivative = self
+            .terms
+            .iter()
+            .filter(|t| t.degree != 0)
+            .map(|t| {
+                let coefficient = t.coefficient.checked_mul(t.degree.into())?;
+                Ok(Term::new(coefficient, t.degree - 1))
+            })
+            .collect::<Result<_, PolysolveError>>()?;
+        let common = self.checked_gcd(&PolynomialFunction::new(derivative))?;
+        Ok(self.checked_long_division(&common)?.0)
+    }
+
+    /// Divides by `(x - root)`, returning the quotient and the remainder `f(root)`.
+    pub fn synthetic_divide(&self, root: Number) -> (PolynomialFunction, Number) {
+        let degree = match self.degree() {
+            Some(degree) => degree,
+            None => return (PolynomialFunction::zero(), 0.into()),
+        };
+
+        let mut quotient = Vec::new();
+        let mut carry: Number = 0.into();
+        let mut terms = self.terms.iter().peekable();
+
+        for d in (0..=degree).rev() {
+            let coefficient = match terms.next_if(|t| t.degree == d) {
+                Some(t) => t.coefficient,
+                None => 0.into(),
+            };
+            carry = carry * root + coefficient;
+            if d > 0 {
+                quotient.push(Term::new(carry, d - 1));
+            }
+        }
+
+        (PolynomialFunction::new(quotient), carry)
+    }
+
+    /// How many times `(x - root)` divides the polynomial; 0 if `root` isn't a root.
+    pub fn root_multiplicity(&self, root: Number) -> usize {
+        if self.terms.is_empty() {
+            return 0;
+        }
+
+        let mut multiplicity = 0;
+        let (mut quotient, mut remainder) = self.synthetic_divide(root);
+
+        while !remainder.not_zero() {
+            multiplicity += 1;
+            (quotient, remainder) = quotient.synthetic_divide(root);
+        }
+
+        multiplicity
+    }
+
+    /// The distinct rational roots, ascending, by the rational root theorem.
+    /// Candidates larger than the coefficients allow any root to be are skipped
+    /// without being evaluated.
+    ///
+    /// Fails with [`PolysolveError::ZeroPolynomial`] for the zero polynomial, which
+    /// every number is a root of, and with [`PolysolveError::Overflow`] when clearing
+    /// denominators leaves the range of `Number`. A candidate whose test would
+    /// overflow is not a root.
+    pub fn roots(&self) -> Result<Vec<Number>, PolysolveError> {
+        let mut roots = BTreeSet::new();
+
+        // with no constant term, 0 is a root; factor out x until there is one
+        let lowest = match self.terms.last() {
+            Some(t) => t.degree,
+            None => return Err(PolysolveError::ZeroPolynomial),
+        };
+        if lowest > 0 {
+            roots.insert(Number::zero());
+        }
+        let reduced = PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(t.coefficient, t.degree - lowest))
+                .collect(),
+        );
+
+        let integers = reduced.integer_terms()?;
+        let to_number = |a: i128| {
+            i64::try_from(a)
+                .map(Number::from)
+                .map_err(|_| PolysolveError::Overflow)
+        };
+        let constant_term = to_number(integers[integers.len() - 1].1)?;
+        let lc_factors = to_number(integers[0].1)?.numer().divisors();
+        let potential_roots = constant_term
+            .factors()
+            .into_iter()
+            .flat_map(|c| lc_factors.iter().map(move |&l| Number::ratio(c, l)));
+
+        for x in potential_roots {
+            if !reduced.beyond_root_bound(x)
+                && Self::divides_exactly(&integers, (*x.numer()).into(), (*x.denom()).into())
+            {
+                roots.insert(x);
+            }
+        }
+
+        Ok(roots.into_iter().collect())
+    }
+
+    // the coefficients in term order, times the lcm of their denominators
+    fn cleared_coefficients(&self) -> Result<Vec<i128>, PolysolveError> {
+        let lcm = self
+            .terms
+            .iter()
+            .try_fold(1_i128, |acc, t| {
+                let d = i128::from(*t.coefficient.denom());
+                (acc / Backing::gcd(&acc, &d)).checked_mul(d)
+            })
+            .ok_or(PolysolveError::Overflow)?;
+        self.terms
+            .iter()
+            .map(|t| {
+                let scale = lcm / i128::from(*t.coefficient.denom());
+                i128::from(*t.coefficient.numer())
+                    .checked_mul(scale)
+                    .ok_or(PolysolveError::Overflow)
+            })
+            .collect()
+    }
+
+    // (degree, coefficient) pairs of a nonzero polynomial with its denominators
+    // cleared by their lcm and x factored out, so the last pair is the constant term
+    fn integer_terms(&self) -> Result<Vec<(u32, i128)>, PolysolveError> {
+        let lowest = self.terms.last().map_or(0, |t| t.degree);
+        Ok(self
+            .terms
+            .iter()
+            .zip(self.cleared_coefficients()?)
+            .map(|(t, a)| ((t.degree - lowest) as u32, a))
+            .collect())
+    }
+
+    // Whether the nonzero c / l, in lowest terms with l > 0, is a root of the
+    // polynomial with these integer_terms. Synthetic division by l x - c only has
+    // integer quotient coefficients at a root, so most candidates stop at the first
+    // remainder. Overflow needs a quotient coefficient past i128 and counts as a
+    // miss too.
+    fn divides_exactly(terms: &[(u32, i128)], c: i128, l: i128) -> bool {
+        let mut quotient = 0_i128;
+        let mut above = terms[0].0 + 1;
+        for &(degree, a) in terms {
+            // the zero coefficients in between multiply the quotient by c / l each
+            let zeros = above - degree - 1;
+            if zeros > 0 && quotient != 0 {
+                quotient = match (l.checked_pow(zeros), c.checked_pow(zeros)) {
+                    (Some(down), Some(up)) if quotient % down == 0 => {
+                        match (quotient / down).checked_mul(up) {
+                            Some(quotient) => quotient,
+                            None => return false,
+                        }
+                    }
+                    _ => return false,
+                };
+            }
+            let sum = match c.checked_mul(quotient).and_then(|cq| cq.checked_add(a)) {
+                Some(sum) => sum,
+                None => return false,
+            };
+            if degree == 0 {
+                return sum == 0;
+            }
+            if sum % l != 0 {
+                return false;
+            }
+            quotient = sum / l;
+            above = degree;
+        }
+        false
+    }
+
+    /// The distinct integer roots, ascending: zero if `x` divides the polynomial,
+    /// then whichever divisors of the lowest nonzero coefficient (with denominators
+    /// cleared) are roots. Empty for the zero polynomial. Fails with
+    /// [`PolysolveError::Overflow`] when clearing denominators leaves the range of
+    /// `Number`; a candidate whose test would overflow is not a root.
+    pub fn integer_roots(&self) -> Result<Vec<i64>, PolysolveError> {
+        let lowest = match self.terms.last() {
+            Some(t) => t,
+            None => return Ok(Vec::new()),
+        };
+        let integers = self.integer_terms()?;
+        let constant = integers[integers.len() - 1].1;
+        let constant = i64::try_from(constant).map_err(|_| PolysolveError::Overflow)?;
+
+        let mut roots = BTreeSet::new();
+        if lowest.degree > 0 {
+            roots.insert(0);
+        }
+        for c in Number::from(constant).factors() {
+            let x = Number::from(c);
+            if !self.beyond_root_bound(x) && Self::divides_exactly(&integers, c.into(), 1) {
+                roots.insert(c);
+            }
+        }
+        Ok(roots.into_iter().collect())
+    }
+
+    // whether |x| exceeds 2 max |a_(n-k) / a_n|^(1/k), which bounds every root
+    // (Fujiwara's bound, tighter than Cauchy's 1 + max |a_i / a_n|); saturating,
+    // so candidates are only ruled out when that is certain
+    fn beyond_root_bound(&self, x: Number) -> bool {
+        let (degree, lead) = match self.terms.first() {
+            Some(t) => (t.degree, &t.coefficient),
+            None => return false,
+        };
+        let numerator = x.numer().unsigned_abs() as u128;
+        let twice_denominator = 2 * *x.denom() as u128;
+
+        // |x|^k |a_n| > 2^k |a_(n-k)|, with both sides over a common denominator
+        for t in &self.terms[1..] {
+            let (k, a) = ((degree - t.degree) as u32, &t.coefficient);
+            let power = numerator.saturating_pow(k);
+            let bound_power = twice_denominator.saturating_pow(k);
+            let lhs = power
+                .saturating_mul(lead.numer().unsigned_abs() as u128)
+                .saturating_mul(*a.denom() as u128);
+            let rhs = bound_power
+                .saturating_mul(a.numer().unsigned_abs() as u128)
+                .saturating_mul(*lead.denom() as u128);
+            if rhs == u128::MAX || lhs <= rhs {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Sign changes in the coefficients of `f(x)` and of `f(-x)`, read highest
+    /// degree first. These bound the number of positive and negative roots.
+    pub fn sign_variations(&self) -> (usize, usize) {
+        let positive = count_sign_changes(self.terms.iter().map(|t| t.coefficient.signum()));
+        // odd degree terms flip sign in f(-x)
+        let negative = count_sign_changes(self.terms.iter().map(|t| {
+            if t.degree % 2 == 0 {
+                t.coefficient.signum()
+            } else {
+                -t.coefficient.signum()
+            }
+        }));
+        (positive, negative)
+    }
+
+    pub fn max_positive_roots(&self) -> usize {
+        self.sign_variations().0
+    }
+
+    pub fn max_negative_roots(&self) -> usize {
+        self.sign_variations().1
+    }
+
+    /// The Sturm sequence: `f`, `f'`, then the negated remainders of successive
+    /// divisions, stopping before the first zero. Each remainder is divided by a
+    /// positive constant down to coprime integer coefficients, which keeps them
+    /// small without moving any sign change. Empty for the zero polynomial. Fails
+    /// with [`PolysolveError::Overflow`] if the coefficients outgrow `Number` anyway.
+    pub fn sturm_chain(&self) -> Result<Vec<PolynomialFunction>, PolysolveError> {
+        if self.terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut chain = vec![self.clone(), self.derivative()];
+        while let Some(last) = chain.last().filter(|p| !p.terms.is_empty()) {
+            let (_, remainder) = chain[chain.len() - 2].checked_long_division(last)?;
+            chain.push((-remainder).checked_primitive_part()?);
+        }
+        chain.pop();
+        Ok(chain)
+    }
+
+    /// The number of distinct real roots in `(a, b]`, counted exactly from the
+    /// sign changes of the Sturm chain at each end. Zero when `a >= b` or for the
+    /// zero polynomial. Fails with [`PolysolveError::Overflow`] like
+    /// [`PolynomialFunction::sturm_chain`], or if evaluating the chain does.
+    pub fn count_real_roots(&self, a: Number, b: Number) -> Result<usize, PolysolveError> {
+        let chain = self.sturm_chain()?;
+        let sign_changes = |x: Number| {
+            let signs = chain
+                .iter()
+                .map(|p| p.checked_signum_at(x))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok::<_, PolysolveError>(count_sign_changes(signs.into_iter()))
+        };
+        Ok(sign_changes(a)?.saturating_sub(sign_changes(b)?))
+    }
+
+    // self divided by a positive constant down to coprime integer coefficients
+    fn checked_primitive_part(&self) -> Result<PolynomialFunction, PolysolveError> {
+        let integers = self.cleared_coefficients()?;
+        let gcd = integers.iter().fold(0, |acc, a| Backing::gcd(&acc, a));
+        self.terms
+            .iter()
+            .zip(integers)
+            .map(|(t, a)| match i64::try_from(a / gcd) {
+                Ok(a) => Ok(Term::new(a.into(), t.degree)),
+                Err(_) => Err(PolysolveError::Overflow),
+            })
+            .collect::<Result<_, _>>()
+            .map(PolynomialFunction::new)
+    }
+
+    // the sign of self(c / l), from l^n self(c / l) computed exactly in i128
+    fn checked_signum_at(&self, x: Number) -> Result<i32, PolysolveError> {
+        fn scaled(terms: &[(u32, i128)], c: i128, l: i128) -> Option<i128> {
+            let n = terms.first().map_or(0, |t| t.0);
+            let mut acc = 0_i128;
+            let mut above = n;
+            for &(degree, a) in terms {
+                let term = a.checked_mul(l.checked_pow(n - degree)?)?;
+                acc = acc
+                    .checked_mul(c.checked_pow(above - degree)?)?
+                    .checked_add(term)?;
+                above = degree;
+            }
+            acc.checked_mul(c.checked_pow(above)?)
+        }
+
+        let terms: Vec<(u32, i128)> = self
+            .terms
+            .iter()
+            .map(|t| t.degree as u32)
+            .zip(self.cleared_coefficients()?)
+            .collect();
+        let value = scaled(&terms, (*x.numer()).into(), (*x.denom()).into())
+            .ok_or(PolysolveError::Overflow)?;
+        Ok(value.signum() as i32)
+    }
+
+    /// Each distinct rational root alongside how many times it divides the polynomial,
+    /// in ascending order of root. Fails like [`PolynomialFunction::roots`].
+    pub fn roots_with_multiplicity(&self) -> Result<Vec<(Number, usize)>, PolysolveError> {
+        Ok(self
+            .roots()?
+            .into_iter()
+            .map(|r| (r, self.root_multiplicity(r)))
+            .collect())
+    }
+
+    /// All distinct real roots, ascending. Rational roots are found exactly and
+    /// deflated out before Newton's method picks up the irrational ones. If the
+    /// rational roots overflow [`Number`], Newton's method finds all of them instead.
+    #[cfg(feature = "std")]
+    pub fn real_roots(&self, tol: f64) -> Vec<f64> {
+        if self.terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut roots: Vec<f64> = Vec::new();
+        // Newton's method creeps toward a repeated root and stops wherever f64
+        // runs out, so strip repeated factors and leave every root simple
+        let mut deflated = self
+            .checked_square_free_part()
+            .unwrap_or_else(|_| self.clone());
+
+        // an Overflow leaves everything to roots_numeric
+        for root in self.roots().unwrap_or_default() {
+            loop {
+                let (quotient, remainder) = deflated.synthetic_divide(root);
+                if remainder.not_zero() {
+                    break;
+                }
+                deflated = quotient;
+            }
+            roots.push(root.to_f64());
+        }
+
+        roots.extend(deflated.roots_numeric(tol, 100));
+
+        roots.sort_by(|a, b| a.total_cmp(b));
+        roots.dedup_by(|a, b| approx_eq(*a, *b, tol));
+        roots
+    }
+
+    /// Newton's method from `x0`, stopping once a step is no larger than `tolerance`.
+    /// `None` if it hits a flat spot, diverges, or runs out of iterations.
+    #[cfg(feature = "std")]
+    pub fn newton_root(&self, x0: f64, tolerance: f64, max_iter: usize) -> Option<f64> {
+        let mut x = x0;
+        for _ in 0..max_iter {
+            let (value, slope) = self.eval_with_derivative(x);
+            if slope == 0.0 {
+                return None;
+            }
+            let next = x - value / slope;
+            if !next.is_finite() {
+                return None;
+            }
+            let step = (next - x).abs();
+            x = next;
+            if step <= tolerance {
+                return Some(x);
+            }
+        }
+        None
+    }
+
+    /// Approximate real roots from Newton's method, seeded at the real parts of the
+    /// companion matrix eigenvalues, or evenly across the Cauchy bound if those fail
+    /// to converge. Repeated roots are found once, from the square-free part.
+    #[cfg(feature = "std")]
+    pub fn roots_numeric(&self, tolerance: f64, max_iter: usize) -> Vec<f64> {
+        let degree = match self.degree() {
+            Some(degree) if degree > 0 => degree,
+            _ => return Vec::new(),
+        };
+
+        // Newton's method only converges linearly to a repeated root
+        let simple = self
+            .checked_square_free_part()
+            .unwrap_or_else(|_| self.clone());
+        // every eigenvalue sits next to a root, where evenly spread seeds
+        // miss roots that are close together or far from the rest
+        let seeds: Vec<f64> = match simple.companion_roots() {
+            Ok(eigenvalues) => eigenvalues.iter().map(|z| z.re).collect(),
+            Err(_) => {
+                let bound = simple.root_bound();
+                let count = 8 * degree as usize;
+                (0..=count)
+                    .map(|i| -bound + 2.0 * bound * i as f64 / count as f64)
+                    .collect()
+            }
+        };
+        let mut roots: Vec<f64> = Vec::new();
+
+        for seed in seeds {
+            if let Some(x) = simple.newton_root(seed, tolerance, max_iter) {
+                if !roots.iter().any(|&r| approx_eq(r, x, tolerance)) {
+                    roots.push(x);
+                }
+            }
+        }
+
+        roots
+    }
+
+    /// All complex roots, as the eigenvalues of the companion matrix. Empty for a
+    /// constant. Fails with [`PolysolveError::NoConvergence`] if the QR iteration
+    /// does.
+    #[cfg(feature = "std")]
+    pub fn companion_roots(&self) -> Result<Vec<Complex>, PolysolveError> {
+        let degree = match self.degree() {
+            Some(degree) if degree > 0 => degree as usize,
+            _ => return Ok(Vec::new()),
+        };
+
+        // divide in f64, where an exact monic polynomial could overflow Number
+        let leading = self.terms[0].coefficient.to_f64();
+        let monic: Vec<f64> = self
+            .coefficients()
+            .into_iter()
+            .take(degree)
+            .map(|c| c.to_f64() / leading)
+            .collect();
+
+        if degree == 1 {
+            return Ok(vec![Complex::new(-monic[0], 0.0)]);
+        }
+
+        let mut matrix = vec![vec![0_f64; degree]; degree];
+        for (i, row) in matrix.iter_mut().enumerate().skip(1) {
+            row[i - 1] = 1.0;
+        }
+        for (j, c) in monic.iter().rev().enumerate() {
+            matrix[0][j] = -c;
+        }
+
+        eigen::hessenberg_eigenvalues(matrix).ok_or(PolysolveError::NoConvergence)
+    }
+
+    /// All complex roots, counted with multiplicity, by Durand–Kerner iteration.
+    #[cfg(feature = "complex")]
+    pub fn roots_complex(&self, tolerance: f64, max_iter: usize) -> Vec<num_complex::Complex64> {
+        use num_complex::Complex64;
+
+        let (degree, monic) = match (self.degree(), self.to_monic()) {
+            (Some(degree), Some(monic)) if degree > 0 => (degree as usize, monic),
+            _ => return Vec::new(),
+        };
+        let monic: Vec<f64> = monic
+            .coefficients()
+            .into_iter()
+            .map(|c| c.to_f64())
+            .collect();
+        let eval = |z: Complex64| {
+            monic
+                .iter()
+                .rev()
+                .fold(Complex64::new(0.0, 0.0), |acc, &c| acc * z + c)
+        };
+
+        // the usual seeds: powers of a point that is neither real nor a root of unity
+        let seed = Complex64::new(0.4, 0.9);
+        let mut roots: Vec<Complex64> = (0..degree as i32).map(|k| seed.powi(k)).collect();
+
+        for _ in 0..max_iter {
+            let mut change = 0_f64;
+            for i in 0..degree {
+                let denominator = (0..degree)
+                    .filter(|&j| j != i)
+                    .fold(Complex64::new(1.0, 0.0), |acc, j| {
+                        acc * (roots[i] - roots[j])
+                    });
+                if denominator == Complex64::new(0.0, 0.0) {
+                    continue;
+                }
+                let step = eval(roots[i]) / denominator;
+                roots[i] -= step;
+                change = change.max(step.norm());
+            }
+            if change <= tolerance {
+                break;
+            }
+        }
+
+        roots
+    }
+
+    /// Renders like `Display`, but with `x^{n}` exponents and `\frac{a}{b}` fractions.
+    pub fn to_latex(&self) -> String {
+        if self.terms.is_empty() {
+            return "0".to_string();
+        }
+
+        let mut out = String::new();
+        for (i, t) in self.terms.iter().enumerate() {
+            out.push_str(match (i, t.coefficient.is_negative()) {
+                (0, true) => "-",
+                (0, false) => "",
+                (_, true) => " - ",
+                (_, false) => " + ",
+            });
+
+            let coefficient = t.coefficient.abs();
+            if t.degree == 0 || coefficient != Number::one() {
+                if coefficient.is_integer() {
+                    out.push_str(&coefficient.numer().to_string());
+                } else {
+                    out.push_str(&format!(
+                        "\\frac{{{}}}{{{}}}",
+                        coefficient.numer(),
+                        coefficient.denom()
+                    ));
+                }
+            }
+            match t.degree {
+                0 => {}
+                1 => out.push('x'),
+                d => out.push_str(&format!("x^{{{}}}", d)),
+            }
+        }
+        out
+    }
+
+    /// Cauchy's bound `1 + max|a_i / a_n|`: every root, real or complex, has
+    /// absolute value below it. Zero for the zero polynomial.
+    #[cfg(feature = "std")]
+    pub fn root_bound(&self) -> f64 {
+        let leading: f64 = match self.terms.first() {
+            Some(t) => t.coefficient.to_f64(),
+            None => return 0.0,
+        };
+
+        1.0 + self.terms[1..]
+            .iter()
+            .map(|t| (t.coefficient.to_f64() / leading).abs())
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Add<&PolynomialFunction> for &PolynomialFunction {
+    type Output = PolynomialFunction;
+
+    fn add(self, rhs: &PolynomialFunction) -> Self::Output {
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .chain(rhs.terms.iter())
+                .map(|t| Term::new(t.coefficient, t.degree))
+                .collect(),
+        )
+    }
+}
+
+impl Add<PolynomialFunction> for PolynomialFunction {
+    type Output = PolynomialFunction;
+
+    fn add(self, rhs: PolynomialFunction) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Neg for PolynomialFunction {
+    type Output = PolynomialFunction;
+
+    fn neg(self) -> Self::Output {
+        PolynomialFunction::new(
+            self.terms
+                .into_iter()
+                .map(|t| Term::new(-t.coefficient, t.degree))
+                .collect(),
+        )
+    }
+}
+
+impl Sub<&PolynomialFunction> for &PolynomialFunction {
+    type Output = PolynomialFunction;
+
+    fn sub(self, rhs: &PolynomialFunction) -> Self::Output {
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .map(|t| Term::new(t.coefficient, t.degree))
+                .chain(
+                    rhs.terms
+                        .iter()
+                        .map(|t| Term::new(-t.coefficient, t.degree)),
+                )
+                .collect(),
+        )
+    }
+}
+
+impl Sub<PolynomialFunction> for PolynomialFunction {
+    type Output = PolynomialFunction;
+
+    fn sub(self, rhs: PolynomialFunction) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl Mul<&PolynomialFunction> for &PolynomialFunction {
+    type Output = PolynomialFunction;
+
+    fn mul(self, rhs: &PolynomialFunction) -> Self::Output {
+        PolynomialFunction::new(
+            self.terms
+                .iter()
+                .flat_map(|a| {
+                    rhs.terms
+                        .iter()
+                        .map(|b| Term::new(a.coefficient * b.coefficient, a.degree + b.degree))
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Mul<PolynomialFunction> for PolynomialFunction {
+    type Output = PolynomialFunction;
+
+    fn mul(self, rhs: PolynomialFunction) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl fmt::Display for PolynomialFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        for (i, t) in self.terms.iter().enumerate() {
+            let negative = t.coefficient.signum() < 0;
+            match (i, negative) {
+                (0, true) => write!(f, "-")?,
+                (0, false) => {}
+                (_, true) => write!(f, " - ")?,
+                (_, false) => write!(f, " + ")?,
+            }
+
+            let coefficient = t.coefficient.abs();
+            if t.degree == 0 {
+                write!(f, "{}", coefficient)?;
+                continue;
+            }
+
+            if coefficient != Number::one() {
+                write!(f, "{}", coefficient)?;
+                if !coefficient.is_integer() {
+                    write!(f, " ")?;
+                }
+            }
+            match t.degree {
+                1 => write!(f, "x")?,
+                d => write!(f, "x^{}", d)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Gaussian elimination with partial pivoting; `None` if the system is singular.
+#[cfg(feature = "std")]
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (row, target) in lower.iter_mut().enumerate() {
+            let factor = target[col] / pivot_row[col];
+            for (t, p) in target[col..].iter_mut().zip(&pivot_row[col..]) {
+                *t -= factor * p;
+            }
+            b[col + 1 + row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0_f64; n];
+    for row in (0..n).rev() {
+        let tail: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Some(x)
+}
+
+// x^k by repeated squaring, since f64::powi needs std
+fn float_pow(mut x: f64, mut k: i32) -> f64 {
+    let mut out = 1_f64;
+    while k > 0 {
+        if k & 1 == 1 {
+            out *= x;
+        }
+        x *= x;
+        k >>= 1;
+    }
+    out
+}
+
+fn count_sign_changes(signs: impl Iterator<Item = i32>) -> usize {
+    let signs: Vec<i32> = signs.filter(|s| *s != 0).collect();
+    signs.windows(2).filter(|w| w[0] != w[1]).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    #[should_panic(expected = "negative degree -1")]
+    fn term_negative_degree() {
+        let _ = Term::new(Number::one(), -1);
+    }
+
+    #[test]
+    fn poly_new() {
+        assert_eq!(PolynomialFunction::new(Vec::new()).terms.len(), 0);
+    }
+
+    #[test]
+    fn simplify() {
+        let terms = vec![
+            Term::new(Number::new(5, 1, true), 0),
+            Term::new(Number::new(10, 1, true), 0),
+            Term::new(Number::new(4, 2, true), 1),
+            Term::new(Number::new(4, 1, true), 1),
+            Term::new(Number::new(4, 2, true), 2),
+            Term::new(Number::new(4, 2, true), 3),
+            Term::new(Number::new(4, 2, false), 3),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+
+        assert_eq!(
+            func.terms,
+            vec![
+                Term::new(Number::new(2, 1, true), 2),
+                Term::new(Number::new(6, 1, true), 1),
+                Term::new(Number::new(15, 1, true), 0),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn evaluate() {
+        let terms = vec![
+            Term::new(Number::new(15, 1, true), 0),
+            Term::new(Number::new(6, 1, true), 1),
+            Term::new(Number::new(2, 1, true), 2),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+
+        assert_eq!(func.evaluate(15_f64), 555_f64);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn evaluate_negative_coefficients() {
+        let func: PolynomialFunction = "-3/4 x^2 - 2".parse().unwrap();
+        assert_eq!(func.evaluate(2.0), -5.0);
+        assert_eq!(func.evaluate(0.0), -2.0);
+    }
+
+    #[test]
+    fn to_latex() {
+        let func: PolynomialFunction = "2x^2 - 5x + 6".parse().unwrap();
+        assert_eq!(func.to_latex(), "2x^{2} - 5x + 6");
+
+        let func: PolynomialFunction = "-1/2 x^3 + x - 3/4".parse().unwrap();
+        assert_eq!(func.to_latex(), "-\\frac{1}{2}x^{3} + x - \\frac{3}{4}");
+
+        assert_eq!(PolynomialFunction::new(Vec::new()).to_latex(), "0");
+        assert_eq!(
+            "-x^10".parse::<PolynomialFunction>().unwrap().to_latex(),
+            "-x^{10}"
+        );
+    }
+
+    #[cfg(feature = "complex")]
+    fn assert_roots_near(mut actual: Vec<num_complex::Complex64>, expected: &[(f64, f64)]) {
+        actual.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        assert_eq!(actual.len(), expected.len());
+        for (z, &(re, im)) in actual.iter().zip(expected) {
+            assert!(
+                (z.re - re).abs() < 1e-9 && (z.im - im).abs() < 1e-9,
+                "{} != {}+{}i",
+                z,
+                re,
+                im
+            );
+        }
+    }
+
+    #[cfg(feature = "complex")]
+    #[test]
+    fn roots_complex() {
+        let func: PolynomialFunction = "x^2 + 1".parse().unwrap();
+        assert_roots_near(func.roots_complex(1e-12, 500), &[(0.0, -1.0), (0.0, 1.0)]);
+
+        // (x - 2)(x^2 + 2x + 5)
+        let func: PolynomialFunction = "x^3 + x - 10".parse().unwrap();
+        assert_roots_near(
+            func.roots_complex(1e-12, 500),
+            &[(-1.0, -2.0), (-1.0, 2.0), (2.0, 0.0)],
+        );
+
+        let func: PolynomialFunction = "2x - 3".parse().unwrap();
+        assert_roots_near(func.roots_complex(1e-12, 500), &[(1.5, 0.0)]);
+        assert!(PolynomialFunction::new(Vec::new())
+            .roots_complex(1e-12, 500)
+            .is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fit_least_squares_line() {
+        // y = 2x + 1 with a little noise
+        let points = [
+            (0.0, 1.05),
+            (1.0, 2.9),
+            (2.0, 5.1),
+            (3.0, 6.95),
+            (4.0, 9.02),
+        ];
+        let fit = PolynomialFunction::fit_least_squares(&points, 1).unwrap();
+        let coeffs: Vec<f64> = fit.coefficients().into_iter().map(Into::into).collect();
+        assert_eq!(coeffs.len(), 2);
+        assert!((coeffs[0] - 1.0).abs() < 0.1, "intercept {}", coeffs[0]);
+        assert!((coeffs[1] - 2.0).abs() < 0.05, "slope {}", coeffs[1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fit_least_squares_exact() {
+        let points: Vec<(f64, f64)> = [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0]
+            .iter()
+            .map(|&x: &f64| (x, 0.5 * x * x - 3.0 * x + 2.0))
+            .collect();
+        assert_eq!(
+            PolynomialFunction::fit_least_squares(&points, 2),
+            Some("1/2 x^2 - 3x + 2".parse().unwrap())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fit_least_squares_large_coefficients() {
+        // past u32::MAX, where coefficients used to be clamped
+        let points: Vec<(f64, f64)> = [0.0, 1.0, 2.0, 3.0]
+            .iter()
+            .map(|&x: &f64| (x, 1e10 * x + 5e9))
+            .collect();
+        let fit = PolynomialFunction::fit_least_squares(&points, 1).unwrap();
+        let coeffs: Vec<f64> = fit.coefficients().into_iter().map(Into::into).collect();
+        assert!((coeffs[0] - 5e9).abs() < 1e-3, "intercept {}", coeffs[0]);
+        assert!((coeffs[1] - 1e10).abs() < 1e-3, "slope {}", coeffs[1]);
+
+        let points = [(0.0, 0.0), (1.0, 1e19), (2.0, 2e19)];
+        assert_eq!(PolynomialFunction::fit_least_squares(&points, 1), None);
+        let points = [(0.0, 0.0), (1.0, f64::NAN), (2.0, 2.0)];
+        assert_eq!(PolynomialFunction::fit_least_squares(&points, 1), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fit_least_squares_underdetermined() {
+        assert_eq!(
+            PolynomialFunction::fit_least_squares(&[(0.0, 1.0), (1.0, 2.0)], 2),
+            None
+        );
+        assert_eq!(
+            PolynomialFunction::fit_least_squares(&[(1.0, 1.0), (1.0, 2.0), (1.0, 3.0)], 1),
+            None
+        );
+    }
+
+    #[test]
+    fn integral() {
+        let func: PolynomialFunction = "3x^2 - 4x + 1/2".parse().unwrap();
+        assert_eq!(func.integral().to_string(), "x^3 - 2x^2 + 1/2 x");
+        assert_eq!(func.integral().derivative(), func);
+        assert_eq!(
+            PolynomialFunction::new(Vec::new()).integral(),
+            PolynomialFunction::new(Vec::new())
+        );
+    }
+
+    #[test]
+    fn integrate() {
+        let func: PolynomialFunction = "x^2".parse().unwrap();
+        assert_eq!(func.integrate(0.into(), 3.into()), Number::from(9));
+        assert_eq!(func.integrate(3.into(), 0.into()), Number::new(9, 1, false));
+
+        // the integral of 2x + 1 from 1/2 to 3/2 is [x^2 + x] = 15/4 - 3/4
+        let func: PolynomialFunction = "2x + 1".parse().unwrap();
+        assert_eq!(
+            func.integrate(Number::new(1, 2, true), Number::new(3, 2, true)),
+            Number::from(3)
+        );
+        assert_eq!(
+            "x^3"
+                .parse::<PolynomialFunction>()
+                .unwrap()
+                .integrate(Number::new(1, 3, false), Number::new(2, 3, true)),
+            Number::new(5, 108, true)
+        );
+    }
+
+    #[test]
+    fn taylor_coefficients() {
+        let func: PolynomialFunction = "x^2".parse().unwrap();
+        assert_eq!(
+            func.taylor_coefficients(1.into()),
+            vec![Number::from(1), 2.into(), 1.into()]
+        );
+
+        // x^3 about 0 keeps its gaps
+        let func: PolynomialFunction = "x^3".parse().unwrap();
+        assert_eq!(
+            func.taylor_coefficients(Number::zero()),
+            vec![Number::zero(), 0.into(), 0.into(), 1.into()]
+        );
+
+        let func: PolynomialFunction = "1/2x^4 - 3x + 2".parse().unwrap();
+        let a = Number::new(3, 2, false);
+        assert_eq!(func.taylor_coefficients(a), func.shift(a).coefficients());
+        assert!(PolynomialFunction::zero().taylor_coefficients(a).is_empty());
+    }
+
+    #[test]
+    fn taylor_at() {
+        // x^2 = (x - 1)^2 + 2(x - 1) + 1
+        let func: PolynomialFunction = "x^2".parse().unwrap();
+        let expansion = func.taylor_at(1.into());
+        assert_eq!(
+            expansion.coefficients(),
+            vec![Number::from(1), 2.into(), 1.into()]
+        );
+
+        let func: PolynomialFunction = "2x^4 - x^3 + 1/2 x - 7".parse().unwrap();
+        let a = Number::new(2, 3, false);
+        assert_eq!(func.taylor_at(a), func.shift(a));
+        assert_eq!(func.taylor_at(Number::zero()), func);
+        assert_eq!(
+            PolynomialFunction::new(Vec::new()).taylor_at(a),
+            PolynomialFunction::new(Vec::new())
+        );
+    }
+
+    #[test]
+    fn sturm_chain() {
+        let func: PolynomialFunction = "x^3 - x".parse().unwrap();
+        let chain: Vec<String> = func
+            .sturm_chain()
+            .unwrap()
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        assert_eq!(chain, vec!["x^3 - x", "3x^2 - 1", "x", "1"]);
+        assert_eq!(
+            PolynomialFunction::new(Vec::new()).sturm_chain(),
+            Ok(Vec::new())
+        );
+
+        // a constant leaves just itself once its zero derivative is dropped
+        let constant: PolynomialFunction = "5".parse().unwrap();
+        assert_eq!(constant.sturm_chain(), Ok(vec![constant]));
+
+        // primitive remainders still need coefficients near 3 * 10^20 here
+        let func: PolynomialFunction = "x^10 - 1/3x^3 + 1/7x - 5".parse().unwrap();
+        assert_eq!(func.sturm_chain(), Err(PolysolveError::Overflow));
+        assert_eq!(
+            func.count_real_roots(Number::from(-2), 2.into()),
+            Err(PolysolveError::Overflow)
+        );
+    }
+
+    #[test]
+    fn count_real_roots() {
+        let func: PolynomialFunction = "x^3 - x".parse().unwrap();
+        assert_eq!(func.count_real_roots(Number::from(-2), 2.into()), Ok(3));
+        assert_eq!(
+            func.count_real_roots(Number::new(1, 2, true), 2.into()),
+            Ok(1)
+        );
+        assert_eq!(func.count_real_roots(2.into(), Number::from(-2)), Ok(0));
+
+        let func: PolynomialFunction = "x^2 + 1".parse().unwrap();
+        assert_eq!(func.count_real_roots(Number::from(-100), 100.into()), Ok(0));
+
+        // f(100) is about 10^20, past i64
+        let func = [2, 3, 5, 7, 11]
+            .into_iter()
+            .map(|c| PolynomialFunction::from_coeffs(&[Number::from(-c), 0.into(), 1.into()]))
+            .fold(PolynomialFunction::constant(1.into()), |acc, p| acc * p);
+        assert_eq!(
+            func.count_real_roots(Number::from(-100), 100.into()),
+            Ok(10)
+        );
+        assert_eq!(
+            func.count_real_roots(0.into(), Number::new(5, 2, true)),
+            Ok(3)
+        );
+    }
+
+    #[test]
+    fn equality_ignores_construction() {
+        let x_plus_one: PolynomialFunction = "x + 1".parse().unwrap();
+        let x_minus_one: PolynomialFunction = "x - 1".parse().unwrap();
+        let expected: PolynomialFunction = "x^2 - 1".parse().unwrap();
+        assert_eq!(x_plus_one.clone() * x_minus_one, expected);
+
+        let unordered = PolynomialFunction::new(vec![
+            Term::new(Number::new(1, 1, false), 0),
+            Term::new(Number::new(3, 1, true), 1),
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(3, 1, false), 1),
+        ]);
+        assert_eq!(unordered, expected);
+        assert_ne!(x_plus_one, expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn evaluate_many() {
+        let func: PolynomialFunction = "2x^3 - 3x^2 + 5".parse().unwrap();
+        let xs = [1.0, 2.0, 3.0];
+        let expected: Vec<f64> = xs.iter().map(|&x| func.evaluate(x)).collect();
+        assert_eq!(func.evaluate_many(&xs), expected);
+        assert_eq!(func.evaluate_many(&[]), Vec::<f64>::new());
+        assert_eq!(
+            PolynomialFunction::new(Vec::new()).evaluate_many(&[4.0]),
+            vec![0.0]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn evaluate_many_cubic() {
+        let func: PolynomialFunction = "1/2x^3 - 2x^2 + 3/4x - 7".parse().unwrap();
+        let xs: Vec<f64> = (0..10).map(|i| i as f64 * 0.7 - 3.1).collect();
+        let batch = func.evaluate_many(&xs);
+        assert_eq!(batch.len(), xs.len());
+        for (&x, y) in xs.iter().zip(batch) {
+            assert!((y - func.evaluate(x)).abs() < 1e-9);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn eval_with_derivative() {
+        let func: PolynomialFunction = "2x^3 - 3x^2 + 5".parse().unwrap();
+        let derivative = func.derivative();
+        for x in [-2.0, -0.5, 0.0, 1.0, 3.0] {
+            assert_eq!(
+                func.eval_with_derivative(x),
+                (func.evaluate(x), derivative.evaluate(x))
+            );
+        }
+        assert_eq!(
+            PolynomialFunction::new(Vec::new()).eval_with_derivative(2.0),
+            (0.0, 0.0)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn eval_with_derivative_matches_derivative() {
+        let func: PolynomialFunction = "x^4 - 1/2 x^3 + 7x - 3".parse().unwrap();
+        let derivative = func.derivative();
+        for x in [-1.5, 0.0, 0.25, 2.0] {
+            let (value, slope) = func.eval_with_derivative(x);
+            assert!(super::approx_eq(value, func.evaluate(x), 1e-12), "{}", x);
+            assert!(
+                super::approx_eq(slope, derivative.evaluate(x), 1e-12),
+                "{}",
+                x
+            );
+        }
+        #[allow(deprecated)]
+        let forwarded = func.eval_and_deriv(2.0);
+        assert_eq!(forwarded, func.eval_with_derivative(2.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn approx_eq() {
+        assert!(super::approx_eq(1.0, 1.0 + 1e-10, 1e-9));
+        assert!(super::approx_eq(-2.5, -2.5, 0.0));
+        assert!(!super::approx_eq(1.0, 1.0 + 1e-8, 1e-9));
+        assert!(!super::approx_eq(0.0, f64::NAN, 1.0));
+
+        // every seed lands on one of the three roots, up to noise
+        let func: PolynomialFunction = "x^3 - 2x".parse().unwrap();
+        assert_eq!(func.real_roots(1e-9).len(), 3);
+        assert_eq!(func.roots_numeric(1e-9, 200).len(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn newton_root() {
+        let func: PolynomialFunction = "x^2 - 2".parse().unwrap();
+        let root = func.newton_root(1.0, 1e-12, 50).unwrap();
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-12);
+        let root = func.newton_root(-5.0, 1e-12, 50).unwrap();
+        assert!((root + std::f64::consts::SQRT_2).abs() < 1e-12);
+
+        // starts on the flat spot at 0, and x^2 + 1 has no real root to find
+        assert_eq!(func.newton_root(0.0, 1e-12, 50), None);
+        let func: PolynomialFunction = "x^2 + 1".parse().unwrap();
+        assert_eq!(func.newton_root(0.5, 1e-12, 50), None);
+    }
+
+    #[test]
+    fn integer_roots() {
+        let func: PolynomialFunction = "x^2 - 5x + 6".parse().unwrap();
+        assert_eq!(func.integer_roots().unwrap(), vec![2, 3]);
+
+        // roots 1/2 and 1/3
+        let func: PolynomialFunction = "6x^2 - 5x + 1".parse().unwrap();
+        assert_eq!(func.integer_roots().unwrap(), Vec::<i64>::new());
+
+        let func = PolynomialFunction::from_roots(&[
+            (-1).into(),
+            0.into(),
+            Number::new(3, 4, false),
+            7.into(),
+            0.into(),
+        ]);
+        assert_eq!(func.integer_roots().unwrap(), vec![-1, 0, 7]);
+
+        let func: PolynomialFunction = "1/2x^2 - 2".parse().unwrap();
+        assert_eq!(func.integer_roots().unwrap(), vec![-2, 2]);
+        assert_eq!(PolynomialFunction::zero().integer_roots(), Ok(Vec::new()));
+        assert_eq!(
+            PolynomialFunction::constant(5.into()).integer_roots(),
+            Ok(Vec::new())
+        );
+    }
+
+    #[test]
+    fn integer_roots_large() {
+        // candidates up to 2^31 would overflow when cubed; the bound stops near 2581
+        let func: PolynomialFunction = "x^3 + 2147483648".parse().unwrap();
+        assert_eq!(func.integer_roots(), Ok(Vec::new()));
+        let func: PolynomialFunction = "x^3 + 1073741824".parse().unwrap();
+        assert_eq!(func.integer_roots(), Ok(vec![-1024]));
+
+        // roots past i32::MAX are kept
+        let func: PolynomialFunction = "x + 5000000000".parse().unwrap();
+        assert_eq!(func.integer_roots(), Ok(vec![-5_000_000_000]));
+        let func: PolynomialFunction = "1/3x^2 - 1000000000x".parse().unwrap();
+        assert_eq!(func.integer_roots(), Ok(vec![0, 3_000_000_000]));
+
+        // -3 * 10^9 is a candidate for this one too, and f(-3 * 10^9) is past i64
+        let func =
+            PolynomialFunction::from_roots(&[Number::from(3_000_000_000_i64), Number::from(-2)]);
+        assert_eq!(func.integer_roots(), Ok(vec![-2, 3_000_000_000]));
+        let func: PolynomialFunction = "x^2 - 2999999998x - 6000000000".parse().unwrap();
+        assert_eq!(func.integer_roots(), Ok(vec![-2, 3_000_000_000]));
+
+        // clearing the denominators of 1/p x^2 for four primes p near 10^9 needs
+        // about 10^36, and then the constant term does not fit
+        let func = PolynomialFunction::from_coeffs(&[
+            7.into(),
+            Number::ratio(1, 1_000_000_007),
+            Number::ratio(1, 1_000_000_009),
+            Number::ratio(1, 1_000_000_021),
+            Number::ratio(1, 1_000_000_033),
+        ]);
+        assert_eq!(func.integer_roots(), Err(PolysolveError::Overflow));
+    }
+
+    #[test]
+    fn root_bound_exact() {
+        // x^2 - 5x + 6 has roots 2 and 3, inside 2 max(5, sqrt 6) = 10
+        let func: PolynomialFunction = "x^2 - 5x + 6".parse().unwrap();
+        assert!(!func.beyond_root_bound(10.into()));
+        assert!(!func.beyond_root_bound(Number::from(-10)));
+        assert!(func.beyond_root_bound(Number::new(21, 2, true)));
+        assert!(func.beyond_root_bound(Number::from(-11)));
+
+        // a large leading coefficient shrinks the bound
+        let func: PolynomialFunction = "1000x^2 - 1".parse().unwrap();
+        assert!(func.beyond_root_bound(Number::new(1, 15, true)));
+        assert!(!func.beyond_root_bound(Number::new(1, 16, true)));
+        assert!(!PolynomialFunction::zero().beyond_root_bound(5.into()));
+    }
+
+    #[test]
+    #[allow(clippy::print_stdout)]
+    fn roots_prints_nothing() {
+        const CHILD: &str = "POLYSOLVE_ROOTS_PRINTS_NOTHING";
+        if std::env::var_os(CHILD).is_some() {
+            let func: PolynomialFunction = "2x^3 - 3x^2 - 11x + 6".parse().unwrap();
+            println!("<<<");
+            func.roots().unwrap();
+            func.roots_with_multiplicity().unwrap();
+            println!(">>>");
+            return;
+        }
+
+        // rerun just this test in a child process, where its stdout can be read
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "types::tests::roots_prints_nothing"])
+            .args(["--nocapture", "--test-threads=1"])
+            .env(CHILD, "1")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("<<<\n>>>\n"), "{}", stdout);
+    }
+
+    #[test]
+    fn roots_without_constant_term() {
+        let func: PolynomialFunction = "x^3 - x".parse().unwrap();
+        assert_eq!(
+            func.roots().unwrap(),
+            vec![Number::from(-1), 0.into(), 1.into()]
+        );
+
+        let func: PolynomialFunction = "2x^4 - 3x^3 + x^2".parse().unwrap();
+        assert_eq!(
+            func.roots().unwrap(),
+            vec![Number::zero(), Number::new(1, 2, true), 1.into()]
+        );
+    }
+
+    #[test]
+    fn roots_result() {
+        // (x + 2)(2x - 1)(x - 3)
+        let func: PolynomialFunction = "2x^3 - 3x^2 - 11x + 6".parse().unwrap();
+        assert_eq!(
+            func.roots(),
+            Ok(vec![Number::from(-2), Number::new(1, 2, true), 3.into()])
+        );
+
+        assert_eq!(
+            PolynomialFunction::zero().roots(),
+            Err(PolysolveError::ZeroPolynomial)
+        );
+
+        // clearing four denominators near 10^6 needs about 10^24, which leaves
+        // a leading coefficient past i64
+        let func = PolynomialFunction::from_coeffs(&[
+            Number::ratio(1, 1_000_003),
+            Number::ratio(1, 1_000_033),
+            Number::ratio(1, 1_000_037),
+            Number::ratio(1, 1_000_039),
+            1.into(),
+        ]);
+        assert_eq!(func.roots(), Err(PolysolveError::Overflow));
+        assert_eq!(
+            func.roots_with_multiplicity(),
+            Err(PolysolveError::Overflow)
+        );
+
+        // the candidate 999999999989 is prime and squares past i64, but lies far
+        // outside the root bound of about 2 * 10^6
+        let func: PolynomialFunction = "x^2 + 999999999989".parse().unwrap();
+        assert_eq!(func.roots(), Ok(Vec::new()));
+        assert_eq!(func.roots_with_multiplicity(), Ok(Vec::new()));
+        let func: PolynomialFunction = "x^2 - 999999999989".parse().unwrap();
+        assert_eq!(func.roots(), Ok(Vec::new()));
+        // factoring a constant term this large by trial division took seconds
+        let func: PolynomialFunction = "x^2 + x - 9223372036854775783".parse().unwrap();
+        assert_eq!(func.roots(), Ok(Vec::new()));
+        assert_eq!(func.integer_roots(), Ok(Vec::new()));
+        let func = PolynomialFunction::from_roots(&[Number::ratio(999_983, 2), 3.into()]);
+        assert_eq!(func.roots(), Ok(vec![3.into(), Number::ratio(999_983, 2)]));
+        // the denominators share a factor, so clearing them takes 10 rather than
+        // 10^20; this is (x^20 - 1) / (10(x - 1))
+        let func = PolynomialFunction::from_coeffs(&[Number::ratio(1, 10); 20]);
+        assert_eq!(func.roots(), Ok(vec![Number::from(-1)]));
+        // 3/2 is within the root bound and (3/2)^40 overflows, but isn't a root
+        let func: PolynomialFunction = "2x^40 - 3".parse().unwrap();
+        assert_eq!(func.roots(), Ok(Vec::new()));
+        // the numeric search still works
+        #[cfg(feature = "std")]
+        assert_eq!(
+            func.real_roots(1e-9).len(),
+            func.roots_numeric(1e-9, 200).len()
+        );
+    }
+
+    #[test]
+    fn roots_zero_root() {
+        let func: PolynomialFunction = "x^2 - 5x".parse().unwrap();
+        assert_eq!(func.roots().unwrap(), vec![Number::zero(), 5.into()]);
+
+        let func: PolynomialFunction = "x^3".parse().unwrap();
+        assert_eq!(func.roots().unwrap(), vec![Number::zero()]);
+        assert_eq!(func.root_multiplicity(Number::zero()), 3);
+
+        let func: PolynomialFunction = "x^2 - x".parse().unwrap();
+        assert_eq!(func.roots(), Ok(vec![Number::zero(), 1.into()]));
+        // a gap between the leading term and the lowest one
+        let func: PolynomialFunction = "3x^4 - 3x^2".parse().unwrap();
+        assert_eq!(func.roots(), Ok(vec![Number::from(-1), 0.into(), 1.into()]));
+
+        let func: PolynomialFunction = "-3x^5 + 12x^3".parse().unwrap();
+        assert_eq!(
+            func.roots().unwrap(),
+            vec![Number::from(-2), 0.into(), 2.into()]
+        );
+    }
+
+    #[test]
+    fn roots_non_monic() {
+        // the leading coefficient's divisors give the denominators
+        let func: PolynomialFunction = "4x^2 - 1".parse().unwrap();
+        assert_eq!(
+            func.roots().unwrap(),
+            vec![Number::new(1, 2, false), Number::new(1, 2, true)]
+        );
+
+        let func = PolynomialFunction::from_roots(&[2.into(), Number::new(1, 3, false)]).reverse();
+        assert_eq!(
+            func.roots().unwrap(),
+            vec![Number::from(-3), Number::new(1, 2, true)]
+        );
+    }
+
+    #[test]
+    fn find_roots() {
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(5, 1, false), 1),
+            Term::new(Number::new(6, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        let mut roots = func.roots().unwrap();
+        roots.sort();
+
+        assert_eq!(
+            roots,
+            vec![Number::new(2, 1, true), Number::new(3, 1, true)]
+        )
+    }
+
+    #[test]
+    fn find_roots_fractional() {
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 3),
+            Term::new(Number::new(9, 2, false), 2),
+            Term::new(Number::new(7, 2, true), 1),
+            Term::new(Number::new(3, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        let mut roots = func.roots().unwrap();
+        roots.sort();
+        assert_eq!(
+            roots,
+            vec![
+                Number::new(1, 2, false),
+                Number::new(2, 1, true),
+                Number::new(3, 1, true)
+            ]
+        )
+    }
+
+    #[test]
+    fn find_roots_irrational() {
+        let terms = vec![
+            Term::new(Number::new(1, 3, true), 3),
+            Term::new(Number::new(1, 9, true), 2),
+            Term::new(Number::new(1, 2, false), 1),
+            Term::new(Number::new(4, 7, true), 0),
+        ];
+        let func = PolynomialFunction::new(terms);
+        // every candidate from the rational root theorem misses; the real
+        // roots are irrational
+        assert_eq!(func.roots().unwrap(), Vec::<Number>::new());
+        assert_eq!(
+            func.count_real_roots(Number::new(3, 1, false), 3.into()),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn synthetic_divide() {
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(5, 1, false), 1),
+            Term::new(Number::new(6, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        let (quotient, remainder) = func.synthetic_divide(Number::new(2, 1, true));
+
+        assert!(!remainder.not_zero());
+        assert_eq!(
+            quotient.terms,
+            vec![
+                Term::new(Number::new(1, 1, true), 1),
+                Term::new(Number::new(3, 1, false), 0),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_real_roots() {
+        // (x - 2)(x^2 - 2)
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 3),
+            Term::new(Number::new(2, 1, false), 2),
+            Term::new(Number::new(2, 1, false), 1),
+            Term::new(Number::new(4, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        let roots = func.real_roots(1e-9);
+
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip([-2_f64.sqrt(), 2_f64.sqrt(), 2.0]) {
+            assert!((root - expected).abs() < 1e-6);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn real_roots_irrational_pairs() {
+        let product = |squares: &[i64]| {
+            squares
+                .iter()
+                .map(|&c| PolynomialFunction::from_coeffs(&[Number::from(-c), 0.into(), 1.into()]))
+                .fold(PolynomialFunction::constant(1.into()), |acc, p| acc * p)
+        };
+
+        for squares in [&[2, 3, 5, 7][..], &[2, 50, 200]] {
+            let roots = product(squares).real_roots(1e-9);
+            let mut expected: Vec<f64> = squares
+                .iter()
+                .flat_map(|&c| [-(c as f64).sqrt(), (c as f64).sqrt()])
+                .collect();
+            expected.sort_by(f64::total_cmp);
+            assert_eq!(roots.len(), expected.len(), "{squares:?}");
+            for (root, expected) in roots.iter().zip(expected) {
+                assert!((root - expected).abs() < 1e-6, "{squares:?}");
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn real_roots_repeated_irrational() {
+        // (x^2 - 2)^2: each root twice, and neither one rational
+        let func: PolynomialFunction = "x^4 - 4x^2 + 4".parse().unwrap();
+        let roots = func.real_roots(1e-9);
+        assert_eq!(roots.len(), 2);
+        assert!((roots[0] + 2_f64.sqrt()).abs() < 1e-9);
+        assert!((roots[1] - 2_f64.sqrt()).abs() < 1e-9);
+
+        // (x - 1)^3 (x^2 - 3)^2
+        let func = PolynomialFunction::from_roots(&[1.into(), 1.into(), 1.into()])
+            * "x^2 - 3".parse::<PolynomialFunction>().unwrap().pow(2);
+        let roots = func.real_roots(1e-9);
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip([-3_f64.sqrt(), 1.0, 3_f64.sqrt()]) {
+            assert!((root - expected).abs() < 1e-9);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_roots_numeric() {
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 3),
+            Term::new(Number::new(2, 1, false), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        let roots = func.roots_numeric(1e-9, 100);
+
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - 2_f64.cbrt()).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roots_numeric_repeated_irrational() {
+        // (x^2 - 2)^2 and (x^3 - 2)^3
+        let func: PolynomialFunction = "x^4 - 4x^2 + 4".parse().unwrap();
+        let mut roots = func.roots_numeric(1e-9, 100);
+        roots.sort_by(f64::total_cmp);
+        assert_eq!(roots.len(), 2);
+        assert!((roots[0] + 2_f64.sqrt()).abs() < 1e-9);
+        assert!((roots[1] - 2_f64.sqrt()).abs() < 1e-9);
+
+        let func = "x^3 - 2".parse::<PolynomialFunction>().unwrap().pow(3);
+        let roots = func.roots_numeric(1e-9, 100);
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - 2_f64.cbrt()).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roots_numeric_close_roots() {
+        for n in [5, 10] {
+            let expected: Vec<Number> = (1..=n).map(Number::from).collect();
+            let func = PolynomialFunction::from_roots(&expected);
+            let mut roots = func.roots_numeric(1e-9, 100);
+            roots.sort_by(f64::total_cmp);
+            assert_eq!(roots.len(), n as usize);
+            for (root, expected) in roots.iter().zip(1..=n) {
+                assert!((root - expected as f64).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roots_numeric_flat_derivative() {
+        // every seed has to survive x = 0, where the derivative of x^2 + 1 vanishes
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(1, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        assert!(func.roots_numeric(1e-9, 100).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_companion_roots() {
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(1, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        let mut roots = func.companion_roots().unwrap();
+        roots.sort_by(|a, b| a.im.total_cmp(&b.im));
+
+        assert_eq!(roots.len(), 2);
+        assert!(roots[0].re.abs() < 1e-9 && (roots[0].im + 1.0).abs() < 1e-9);
+        assert!(roots[1].re.abs() < 1e-9 && (roots[1].im - 1.0).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_companion_roots_cubic() {
+        // 2(x - 1)(x + 2)(x - 1/2)
+        let terms = vec![
+            Term::new(Number::new(2, 1, true), 3),
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(5, 1, false), 1),
+            Term::new(Number::new(2, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        let mut roots = func.companion_roots().unwrap();
+        roots.sort_by(|a, b| a.re.total_cmp(&b.re));
+
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip([-2.0, 0.5, 1.0]) {
+            assert!((root.re - expected).abs() < 1e-9);
+            assert!(root.im.abs() < 1e-9);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_companion_roots_linear() {
+        let terms = vec![
+            Term::new(Number::new(2, 1, true), 1),
+            Term::new(Number::new(3, 1, false), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        assert_eq!(func.companion_roots(), Ok(vec![Complex::new(1.5, 0.0)]));
+        assert_eq!(
+            PolynomialFunction::constant(3.into()).companion_roots(),
+            Ok(Vec::new())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_companion_roots_quartic() {
+        // (x^2 + 1)(x^2 - 4)
+        let func: PolynomialFunction = "x^4 - 3x^2 - 4".parse().unwrap();
+        let mut roots = func.companion_roots().unwrap();
+        roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+
+        let expected = [(-2.0, 0.0), (0.0, -1.0), (0.0, 1.0), (2.0, 0.0)];
+        assert_eq!(roots.len(), 4);
+        for (root, (re, im)) in roots.iter().zip(expected) {
+            assert!((root.re - re).abs() < 1e-9 && (root.im - im).abs() < 1e-9);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn find_companion_roots_conjugate_pairs() {
+        // (x - 1)(x + 2)(x - 3)(x^2 + 2x + 5), with -1 ± 2i from the last factor
+        let func = PolynomialFunction::from_roots(&[1.into(), (-2).into(), 3.into()])
+            * "x^2 + 2x + 5".parse::<PolynomialFunction>().unwrap();
+        let roots = func.companion_roots().unwrap();
+        assert_eq!(roots.len(), 5);
+
+        let expected = [
+            (1.0, 0.0),
+            (-2.0, 0.0),
+            (3.0, 0.0),
+            (-1.0, 2.0),
+            (-1.0, -2.0),
+        ];
+        for (re, im) in expected {
+            assert!(roots
+                .iter()
+                .any(|r| (r.re - re).abs() < 1e-9 && (r.im - im).abs() < 1e-9));
+        }
+        // complex roots of a real polynomial come in conjugate pairs
+        for root in &roots {
+            assert!(roots
+                .iter()
+                .any(|r| (r.re - root.re).abs() < 1e-9 && (r.im + root.im).abs() < 1e-9));
+        }
+    }
+
+    #[test]
+    fn descartes_sign_variations() {
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(5, 1, false), 1),
+            Term::new(Number::new(6, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        assert_eq!(func.sign_variations(), (2, 0));
+        assert_eq!(func.max_positive_roots(), 2);
+        assert_eq!(func.max_negative_roots(), 0);
+
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 3),
+            Term::new(Number::new(1, 1, true), 1),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        assert_eq!(func.max_positive_roots(), 0);
+        assert_eq!(func.max_negative_roots(), 0);
+    }
+
+    #[test]
+    fn multiplicity() {
+        // (x - 2)^3
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 3),
+            Term::new(Number::new(6, 1, false), 2),
+            Term::new(Number::new(12, 1, true), 1),
+            Term::new(Number::new(8, 1, false), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        assert_eq!(func.root_multiplicity(Number::new(2, 1, true)), 3);
+        assert_eq!(func.root_multiplicity(Number::new(2, 1, false)), 0);
+
+        let terms = vec![
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(5, 1, false), 1),
+            Term::new(Number::new(6, 1, true), 0),
+        ];
+
+        let func = PolynomialFunction::new(terms);
+        assert_eq!(func.root_multiplicity(Number::new(2, 1, true)), 1);
+        assert_eq!(func.root_multiplicity(Number::new(3, 1, true)), 1);
+    }
+
+    #[test]
+    fn multiplicity_zero_polynomial() {
+        let func = PolynomialFunction::new(Vec::new());
+        assert_eq!(func.root_multiplicity(Number::new(2, 1, true)), 0);
+    }
+
+    #[test]
+    fn display() {
+        let terms = vec![
+            Term::new(Number::new(2, 1, true), 2),
+            Term::new(Number::new(5, 1, false), 1),
+            Term::new(Number::new(6, 1, true), 0),
+        ];
+        assert_eq!(PolynomialFunction::new(terms).to_string(), "2x^2 - 5x + 6");
+
+        let terms = vec![
+            Term::new(Number::new(1, 1, false), 3),
+            Term::new(Number::new(1, 2, true), 1),
+            Term::new(Number::new(3, 4, false), 0),
+        ];
+        assert_eq!(
+            PolynomialFunction::new(terms).to_string(),
+            "-x^3 + 1/2 x - 3/4"
+        );
+    }
+
+    #[test]
+    fn display_constant() {
+        let terms = vec![Term::new(Number::new(7, 1, true), 0)];
+        assert_eq!(PolynomialFunction::new(terms).to_string(), "7");
+
+        let terms = vec![Term::new(Number::new(1, 1, false), 0)];
+        assert_eq!(PolynomialFunction::new(terms).to_string(), "-1");
+    }
+
+    #[test]
+    fn display_zero() {
+        assert_eq!(PolynomialFunction::new(Vec::new()).to_string(), "0");
+    }
+
+    #[test]
+    fn multiply() {
+        let lhs = PolynomialFunction::new(vec![
+            Term::new(Number::new(2, 1, true), 1),
+            Term::new(Number::new(1, 1, false), 0),
+        ]);
+        let rhs = PolynomialFunction::new(vec![
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(3, 1, true), 0),
+        ]);
+
+        assert_eq!(
+            (lhs * rhs).terms,
+            vec![
+                Term::new(Number::new(2, 1, true), 3),
+                Term::new(Number::new(1, 1, false), 2),
+                Term::new(Number::new(6, 1, true), 1),
+                Term::new(Number::new(3, 1, false), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_roots() {
+        let func = PolynomialFunction::from_roots(&[2.into(), 3.into()]);
+
+        assert_eq!(
+            func.terms,
+            vec![
+                Term::new(Number::new(1, 1, true), 2),
+                Term::new(Number::new(5, 1, false), 1),
+                Term::new(Number::new(6, 1, true), 0),
+            ]
+        );
+
+        let mut roots = func.roots().unwrap();
+        roots.sort();
+        assert_eq!(
+            roots,
+            vec![Number::new(2, 1, true), Number::new(3, 1, true)]
+        );
+    }
+
+    #[test]
+    fn from_roots_fractional() {
+        let func = PolynomialFunction::from_roots(&[Number::new(1, 2, false), 2.into()]);
+        assert_eq!(func.to_string(), "x^2 - 3/2 x - 1");
+
+        let mut roots = func.roots().unwrap();
+        roots.sort();
+        assert_eq!(
+            roots,
+            vec![Number::new(1, 2, false), Number::new(2, 1, true)]
+        );
+    }
+
+    #[test]
+    fn from_no_roots() {
+        assert_eq!(PolynomialFunction::from_roots(&[]).to_string(), "1");
+    }
+
+    #[test]
+    fn add() {
+        let lhs: PolynomialFunction = "x^2 - 3x + 1".parse().unwrap();
+        let rhs: PolynomialFunction = "2x^3 + 3x - 1".parse().unwrap();
+
+        assert_eq!((lhs + rhs).to_string(), "2x^3 + x^2");
+    }
+
+    #[test]
+    fn sub() {
+        let lhs: PolynomialFunction = "x^2 - 3x + 1".parse().unwrap();
+        let rhs: PolynomialFunction = "2x^3 - 3x + 1".parse().unwrap();
+
+        assert_eq!((&lhs - &rhs).to_string(), "-2x^3 + x^2");
+        assert!((lhs.clone() - lhs).terms.is_empty());
+    }
+
+    #[test]
+    fn compose() {
+        let f: PolynomialFunction = "x^2 + 1".parse().unwrap();
+        let g: PolynomialFunction = "x + 1".parse().unwrap();
+
+        assert_eq!(
+            f.compose(&g).terms,
+            vec![
+                Term::new(Number::new(1, 1, true), 2),
+                Term::new(Number::new(2, 1, true), 1),
+                Term::new(Number::new(2, 1, true), 0),
+            ]
+        );
+        assert_eq!(g.compose(&f).to_string(), "x^2 + 2");
+    }
+
+    #[test]
+    fn compose_constant() {
+        let f: PolynomialFunction = "x^2 - 2x + 1".parse().unwrap();
+        let g: PolynomialFunction = "3".parse().unwrap();
+
+        assert_eq!(
+            f.compose(&g).terms,
+            vec![Term::new(Number::new(4, 1, true), 0)]
+        );
+        assert_eq!(
+            g.compose(&f).terms,
+            vec![Term::new(Number::new(3, 1, true), 0)]
+        );
+    }
+
+    #[test]
+    fn from_coeffs() {
+        let func = PolynomialFunction::from_coeffs(&[
+            Number::new(6, 1, true),
+            Number::new(5, 1, false),
+            Number::new(1, 1, true),
+        ]);
+
+        assert_eq!(
+            func.terms,
+            vec![
+                Term::new(Number::new(1, 1, true), 2),
+                Term::new(Number::new(5, 1, false), 1),
+                Term::new(Number::new(6, 1, true), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_coeffs_skips_zeros() {
+        let func =
+            PolynomialFunction::from_coeffs(&[1.into(), 0.into(), 0.into(), 1.into(), 0.into()]);
+        assert_eq!(func.to_string(), "x^3 + 1");
+
+        assert_eq!(PolynomialFunction::from_coeffs(&[]).degree(), None);
+    }
+
+    #[test]
+    fn shift() {
+        let func: PolynomialFunction = "x^2".parse().unwrap();
+
+        assert_eq!(
+            func.shift(1.into()).terms,
+            vec![
+                Term::new(Number::new(1, 1, true), 2),
+                Term::new(Number::new(2, 1, true), 1),
+                Term::new(Number::new(1, 1, true), 0),
+            ]
+        );
+        assert_eq!(
+            func.shift(Number::new(1, 2, false)).to_string(),
+            "x^2 - x + 1/4"
+        );
+    }
+
+    #[test]
+    fn shift_by_zero() {
+        let func: PolynomialFunction = "2x^3 - x + 5".parse().unwrap();
+        assert_eq!(func.shift(0.into()).terms, func.terms);
+    }
+
+    #[test]
+    fn coefficients() {
+        let func: PolynomialFunction = "x^3 + 1".parse().unwrap();
+        assert_eq!(
+            func.coefficients(),
+            vec![Number::one(), Number::zero(), Number::zero(), Number::one()]
+        );
+
+        let coeffs = vec![
+            Number::new(6, 1, true),
+            Number::new(5, 1, false),
+            Number::one(),
+        ];
+        assert_eq!(
+            PolynomialFunction::from_coeffs(&coeffs).coefficients(),
+            coeffs
+        );
+    }
+
+    #[test]
+    fn coefficients_zero_polynomial() {
+        assert!(PolynomialFunction::new(Vec::new())
+            .coefficients()
+            .is_empty());
+    }
+
+    #[test]
+    fn to_monic() {
+        let func: PolynomialFunction = "2x^2 + 4x + 2".parse().unwrap();
+        assert_eq!(
+            func.to_monic().unwrap().terms,
+            vec![
+                Term::new(Number::new(1, 1, true), 2),
+                Term::new(Number::new(2, 1, true), 1),
+                Term::new(Number::new(1, 1, true), 0),
+            ]
+        );
+
+        let func: PolynomialFunction = "-4x^3 + 2x - 3".parse().unwrap();
+        assert_eq!(func.to_monic().unwrap().to_string(), "x^3 - 1/2 x + 3/4");
+
+        let func: PolynomialFunction = "2x^2 - 4x + 6".parse().unwrap();
+        assert_eq!(func.to_monic().unwrap().to_string(), "x^2 - 2x + 3");
+    }
+
+    #[test]
+    fn to_monic_zero_polynomial() {
+        let func = PolynomialFunction::new(Vec::new());
+        assert_eq!(func.leading_coefficient(), Number::zero());
+        assert!(func.to_monic().is_none());
+    }
+
+    #[test]
+    fn evaluate_exact_matches_termwise() {
+        let func: PolynomialFunction = "3x^6 - 2x^5 + 1/2 x^3 - x^2 + 7x - 4".parse().unwrap();
+
+        for x in [
+            Number::zero(),
+            Number::one(),
+            Number::new(3, 1, false),
+            Number::new(1, 2, true),
+            Number::new(2, 3, false),
+        ] {
+            let termwise = func.terms.iter().fold(Number::zero(), |acc, t| {
+                acc + x.pow(t.degree) * t.coefficient
+            });
+            assert_eq!(func.evaluate_exact(x), termwise);
+        }
+
+        assert_eq!(
+            func.evaluate_exact(Number::new(3, 1, false)).to_string(),
+            "5251/2"
+        );
+    }
+
+    #[test]
+    fn evaluate_sparse() {
+        // a pass per degree would take a hundred million steps
+        let func: PolynomialFunction = "x^100000000 - 1".parse().unwrap();
+        assert_eq!(func.evaluate_exact(1.into()), Number::zero());
+        assert_eq!(func.evaluate_exact(Number::from(-1)), Number::zero());
+        assert_eq!(func.roots(), Ok(vec![Number::from(-1), 1.into()]));
+        assert_eq!(func.integer_roots(), Ok(vec![-1, 1]));
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(func.eval_with_derivative(1.0), (0.0, 1e8));
+            assert_eq!(func.evaluate_many(&[-1.0, 0.0]), vec![0.0, -1.0]);
+        }
+
+        let func: PolynomialFunction = "2x^9 - 1/3 x^4 + 5x".parse().unwrap();
+        for x in [Number::zero(), Number::new(3, 2, true), Number::from(-2)] {
+            let termwise = func.terms.iter().fold(Number::zero(), |acc, t| {
+                acc + x.pow(t.degree) * t.coefficient
+            });
+            assert_eq!(func.evaluate_exact(x), termwise);
+        }
+        #[cfg(feature = "std")]
+        for x in [0.0, 1.5, -2.0] {
+            let derivative = func.derivative().evaluate(x);
+            let (value, slope) = func.eval_with_derivative(x);
+            assert!(super::approx_eq(value, func.evaluate(x), 1e-12));
+            assert!(super::approx_eq(slope, derivative, 1e-12));
+        }
+    }
+
+    #[test]
+    fn div_rem() {
+        let func: PolynomialFunction = "2x^4 - 3x^3 + x - 5".parse().unwrap();
+        let divisor: PolynomialFunction = "x^2 + 1".parse().unwrap();
+        let (quotient, remainder) = func.div_rem(&divisor).unwrap();
+
+        assert_eq!(quotient.to_string(), "2x^2 - 3x - 2");
+        assert_eq!(remainder.to_string(), "4x - 3");
+
+        let func: PolynomialFunction = "x^3 - 1".parse().unwrap();
+        let divisor: PolynomialFunction = "2x - 2".parse().unwrap();
+        let (quotient, remainder) = func.div_rem(&divisor).unwrap();
+
+        assert_eq!(quotient.to_string(), "1/2 x^2 + 1/2 x + 1/2");
+        assert!(remainder.terms.is_empty());
+    }
+
+    #[test]
+    fn div_rem_lower_degree() {
+        let func: PolynomialFunction = "x + 1".parse().unwrap();
+        let divisor: PolynomialFunction = "x^2".parse().unwrap();
+        let (quotient, remainder) = func.div_rem(&divisor).unwrap();
+
+        assert!(quotient.terms.is_empty());
+        assert_eq!(remainder.terms, func.terms);
+    }
+
+    #[test]
+    fn div_rem_by_zero() {
+        let func: PolynomialFunction = "x + 1".parse().unwrap();
+        assert_eq!(
+            func.div_rem(&PolynomialFunction::new(Vec::new())),
+            Err(PolysolveError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn gcd() {
+        let a: PolynomialFunction = "x^2 - 1".parse().unwrap();
+        let b: PolynomialFunction = "x^2 - 2x + 1".parse().unwrap();
+        assert_eq!(a.gcd(&b).to_string(), "x - 1");
+        assert_eq!(b.gcd(&a).to_string(), "x - 1");
+
+        let a: PolynomialFunction = "3x^3 - 3x".parse().unwrap();
+        let b: PolynomialFunction = "2x^2 + 2x".parse().unwrap();
+        assert_eq!(a.gcd(&b).to_string(), "x^2 + x");
+    }
+
+    #[test]
+    fn gcd_coprime() {
+        let a: PolynomialFunction = "x^2 + 1".parse().unwrap();
+        let b: PolynomialFunction = "x - 3".parse().unwrap();
+        assert_eq!(a.gcd(&b).to_string(), "1");
+    }
+
+    #[test]
+    fn gcd_with_zero() {
+        let a: PolynomialFunction = "2x - 4".parse().unwrap();
+        let zero = PolynomialFunction::new(Vec::new());
+
+        assert_eq!(a.gcd(&zero).to_string(), "x - 2");
+        assert_eq!(zero.gcd(&a).to_string(), "x - 2");
+        assert_eq!(zero.gcd(&zero).to_string(), "0");
+    }
+
+    #[test]
+    fn square_free_part() {
+        // (x - 1)^2 (x - 2)
+        let func: PolynomialFunction = "x^3 - 4x^2 + 5x - 2".parse().unwrap();
+        assert_eq!(func.square_free_part().to_string(), "x^2 - 3x + 2");
+
+        // 2(x + 1)^3
+        let func: PolynomialFunction = "2x^3 + 6x^2 + 6x + 2".parse().unwrap();
+        assert_eq!(func.square_free_part().to_string(), "2x + 2");
+    }
+
+    #[test]
+    fn square_free_part_already_square_free() {
+        let func: PolynomialFunction = "x^2 - 5x + 6".parse().unwrap();
+        assert_eq!(func.square_free_part().terms, func.terms);
+
+        assert!(PolynomialFunction::new(Vec::new())
+            .square_free_part()
+            .terms
+            .is_empty());
+    }
+
+    #[test]
+    fn simplify_many_terms() {
+        let terms = (0..400)
+            .map(|i| Term::new(Number::new(1, 1, i % 8 != 7), i % 4))
+            .collect();
+
+        let func = PolynomialFunction::new(terms);
+
+        // degree 3 gets 50 positive and 50 negative ones
+        assert_eq!(
+            func.terms,
+            vec![
+                Term::new(Number::new(100, 1, true), 2),
+                Term::new(Number::new(100, 1, true), 1),
+                Term::new(Number::new(100, 1, true), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn simplify_is_order_independent() {
+        let terms: Vec<Term> = [
+            (1, 3, 5),
+            (2, 1, 0),
+            (1, 6, 5),
+            (-1, 1, 2),
+            (3, 1, 0),
+            (1, 1, 2),
+        ]
+        .iter()
+        .map(|&(n, d, degree)| Term::new(Number::from((n, d)), degree))
+        .collect();
+        let expected = vec![
+            Term::new(Number::new(1, 2, true), 5),
+            Term::new(Number::new(5, 1, true), 0),
+        ];
+
+        assert_eq!(PolynomialFunction::new(terms.clone()).terms, expected);
+        let reversed: Vec<Term> = terms.into_iter().rev().collect();
+        assert_eq!(PolynomialFunction::new(reversed).terms, expected);
+    }
+
+    #[test]
+    fn sign_variations_skip_gaps() {
+        // x^5 - x^2 - 1: the missing degrees don't count as sign changes
+        let func: PolynomialFunction = "x^5 - x^2 - 1".parse().unwrap();
+        assert_eq!(func.sign_variations(), (1, 0));
+
+        let func: PolynomialFunction = "x^4 + 3x^3 + 2x + 7".parse().unwrap();
+        assert_eq!(func.sign_variations().0, 0);
+    }
+
+    #[test]
+    fn compose_identity() {
+        let f: PolynomialFunction = "3x^4 - 1/2 x^3 + x - 8".parse().unwrap();
+        let identity: PolynomialFunction = "x".parse().unwrap();
+
+        assert_eq!(f.compose(&identity).terms, f.terms);
+        assert_eq!(identity.compose(&f).terms, f.terms);
+    }
+
+    #[test]
+    fn shift_matches_compose() {
+        let func: PolynomialFunction = "2x^4 - x^3 + 1/3 x - 5".parse().unwrap();
+
+        for a in [
+            Number::new(2, 1, true),
+            Number::new(3, 1, false),
+            Number::new(2, 3, false),
+        ] {
+            let linear = PolynomialFunction::new(vec![Term::new(1.into(), 1), Term::new(a, 0)]);
+            assert_eq!(func.shift(a).to_string(), func.compose(&linear).to_string());
+        }
+
+        assert!(PolynomialFunction::new(Vec::new())
+            .shift(1.into())
+            .terms
+            .is_empty());
+    }
+
+    #[test]
+    fn scale_variable() {
+        let func: PolynomialFunction = "2x^2 + 3x + 1".parse().unwrap();
+
+        assert_eq!(func.scale_variable(2.into()).to_string(), "8x^2 + 6x + 1");
+        assert_eq!(
+            func.scale_variable(Number::new(1, 2, false)).to_string(),
+            "1/2 x^2 - 3/2 x + 1"
+        );
+        assert_eq!(func.scale_variable(0.into()).to_string(), "1");
+    }
+
+    #[test]
+    fn negate() {
+        let func: PolynomialFunction = "x^2 - 3x + 1/2".parse().unwrap();
+        assert_eq!((-func).to_string(), "-x^2 + 3x - 1/2");
+    }
+
+    #[test]
+    fn sturm_subintervals() {
+        // (x - 1)(x - 2)(x - 3)
+        let func: PolynomialFunction = "x^3 - 6x^2 + 11x - 6".parse().unwrap();
+
+        assert_eq!(
+            func.count_real_roots(Number::new(10, 1, false), 10.into()),
+            Ok(3)
+        );
+        assert_eq!(
+            func.count_real_roots(0.into(), Number::new(3, 2, true)),
+            Ok(1)
+        );
+        assert_eq!(func.count_real_roots(1.into(), 3.into()), Ok(2));
+        assert_eq!(
+            func.count_real_roots(Number::new(3, 2, true), Number::new(5, 2, true)),
+            Ok(1)
+        );
+        assert_eq!(func.count_real_roots(3.into(), 10.into()), Ok(0));
+    }
+
+    #[test]
+    fn sturm_ignores_complex_and_repeated_roots() {
+        // (x^2 + 1)(x - 2)^2
+        let func: PolynomialFunction = "x^4 - 4x^3 + 5x^2 - 4x + 4".parse().unwrap();
+
+        assert_eq!(
+            func.count_real_roots(Number::new(10, 1, false), 10.into()),
+            Ok(1)
+        );
+        assert_eq!(
+            func.count_real_roots(Number::new(10, 1, false), 1.into()),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn interpolate() {
+        let points = [
+            (0.into(), 0.into()),
+            (1.into(), 1.into()),
+            (2.into(), 4.into()),
+        ];
+        assert_eq!(PolynomialFunction::interpolate(&points).to_string(), "x^2");
+
+        let points = [
+            (Number::new(1, 1, false), Number::new(5, 1, false)),
+            (Number::new(1, 2, true), Number::new(1, 2, true)),
+            (2.into(), 1.into()),
+            (3.into(), 7.into()),
+        ];
+        let func = PolynomialFunction::interpolate(&points);
+        for (x, y) in points {
+            assert_eq!(func.evaluate_exact(x).to_string(), y.to_string());
+        }
+        assert_eq!(func.degree(), Some(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot interpolate through two points with x = 2")]
+    fn interpolate_duplicate_x() {
+        let points = [
+            (2.into(), 0.into()),
+            (1.into(), 1.into()),
+            (2.into(), 4.into()),
+        ];
+        PolynomialFunction::interpolate(&points);
+    }
+
+    #[test]
+    fn iter_terms() {
+        let func: PolynomialFunction = "3 - 2x^4 + 1/2 x".parse().unwrap();
+
+        assert_eq!(
+            func.iter_terms().collect::<Vec<_>>(),
+            vec![
+                (4, Number::new(2, 1, false)),
+                (1, Number::new(1, 2, true)),
+                (0, Number::new(3, 1, true)),
+            ]
+        );
+        assert_eq!(
+            func.coefficients(),
+            vec![
+                Number::new(3, 1, true),
+                Number::new(1, 2, true),
+                Number::zero(),
+                Number::zero(),
+                Number::new(2, 1, false),
+            ]
+        );
+        assert_eq!(PolynomialFunction::new(Vec::new()).iter_terms().count(), 0);
+    }
+
+    #[test]
+    fn content_and_primitive_part() {
+        let func: PolynomialFunction = "4x^2 + 8x + 12".parse().unwrap();
+        assert_eq!(func.content(), Number::new(4, 1, true));
+        assert_eq!(func.primitive_part().to_string(), "x^2 + 2x + 3");
+
+        let func: PolynomialFunction = "-6x^3 + 9x".parse().unwrap();
+        assert_eq!(func.content(), Number::new(3, 1, false));
+        assert_eq!(func.primitive_part().to_string(), "2x^3 - 3x");
+
+        let func: PolynomialFunction = "6x^2 + 12x + 18".parse().unwrap();
+        assert_eq!(func.content(), Number::from(6));
+        assert_eq!(func.primitive_part(), "x^2 + 2x + 3".parse().unwrap());
+        assert_eq!(func.primitive_part().scale(func.content()), func);
+    }
+
+    #[test]
+    fn content_fractional() {
+        let func: PolynomialFunction = "1/2 x + 1/3".parse().unwrap();
+        assert_eq!(func.content(), Number::new(1, 6, true));
+        assert_eq!(func.primitive_part().to_string(), "3x + 2");
+
+        let func: PolynomialFunction = "4/3 x^2 - 2/9".parse().unwrap();
+        assert_eq!(func.primitive_part().to_string(), "6x^2 - 1");
+    }
+
+    #[test]
+    fn content_zero_polynomial() {
+        let func = PolynomialFunction::new(Vec::new());
+        assert_eq!(func.content(), Number::zero());
+        assert!(func.primitive_part().terms.is_empty());
+    }
+
+    #[test]
+    fn coefficient_lookup() {
+        let func: PolynomialFunction = "2x^5 - 3x^2 + 1/4".parse().unwrap();
+
+        assert_eq!(func.coefficient(5), Number::new(2, 1, true));
+        assert_eq!(func.coefficient(2), Number::new(3, 1, false));
+        assert_eq!(func.coefficient(0), Number::new(1, 4, true));
+        assert_eq!(func.coefficient(1), Number::zero());
+        assert_eq!(func.coefficient(9), Number::zero());
+        assert_eq!(
+            PolynomialFunction::new(Vec::new()).coefficient(0),
+            Number::zero()
+        );
+    }
+
+    #[test]
+    fn gcd_up_to_scaling() {
+        // 3(x - 1)(x + 2)(x - 3) and 5(x - 1)(x + 2)(x + 4)
+        let a: PolynomialFunction = "3x^3 - 6x^2 - 15x + 18".parse().unwrap();
+        let b: PolynomialFunction = "5x^3 + 25x^2 + 10x - 40".parse().unwrap();
+
+        assert_eq!(a.gcd(&b).to_string(), "x^2 + x - 2");
+    }
+
+    #[test]
+    fn square_free_part_and_multiplicity() {
+        // (x - 2)^2 (x - 3)
+        let func: PolynomialFunction = "x^3 - 7x^2 + 16x - 12".parse().unwrap();
+
+        assert_eq!(func.square_free_part().to_string(), "x^2 - 5x + 6");
+        assert_eq!(func.root_multiplicity(2.into()), 2);
+        assert_eq!(func.root_multiplicity(3.into()), 1);
+    }
+
+    #[test]
+    fn evaluate_at() {
+        let func: PolynomialFunction = "2x^2 - 5x + 6".parse().unwrap();
+
+        assert_eq!(func.evaluate_at(3u32), Number::new(9, 1, true));
+        assert_eq!(
+            func.evaluate_at(Number::new(1, 2, true)),
+            Number::new(4, 1, true)
+        );
+        assert_eq!(
+            func.evaluate_at(Number::new(1, 3, false)).to_string(),
+            "71/9"
+        );
+    }
+
+    #[test]
+    fn roots_with_multiplicity() {
+        // (x - 2)^2 (x + 1)
+        let func: PolynomialFunction = "x^3 - 3x^2 + 4".parse().unwrap();
+
+        assert_eq!(
+            func.roots_with_multiplicity().unwrap(),
+            vec![(Number::new(1, 1, false), 1), (Number::new(2, 1, true), 2)]
+        );
+
+        let mut roots = func.roots().unwrap();
+        roots.sort();
+        assert_eq!(
+            roots,
+            vec![Number::new(1, 1, false), Number::new(2, 1, true)]
+        );
+    }
+
+    #[test]
+    fn discriminant() {
+        let func: PolynomialFunction = "x^2 - 5x + 6".parse().unwrap();
+        assert_eq!(func.discriminant(), Some(Number::one()));
+
+        let func: PolynomialFunction = "x^2 - 4x + 4".parse().unwrap();
+        assert_eq!(func.discriminant().map(|d| d.signum()), Some(0));
+
+        let func: PolynomialFunction = "x^2 + 1".parse().unwrap();
+        assert_eq!(
+            func.discriminant().map(|d| d.to_string()),
+            Some("-4".to_string())
+        );
+    }
+
+    #[test]
+    fn discriminant_cubic() {
+        // (x - 1)(x - 2)(x - 3)
+        let func: PolynomialFunction = "x^3 - 6x^2 + 11x - 6".parse().unwrap();
+        assert_eq!(func.discriminant(), Some(Number::new(4, 1, true)));
+
+        let func: PolynomialFunction = "x^3 + x".parse().unwrap();
+        assert_eq!(
+            func.discriminant().map(|d| d.to_string()),
+            Some("-4".to_string())
+        );
+
+        let func: PolynomialFunction = "x^3 - 3x^2 + 4".parse().unwrap();
+        assert_eq!(func.discriminant().map(|d| d.signum()), Some(0));
+    }
+
+    #[test]
+    fn discriminant_other_degrees() {
+        assert_eq!(
+            "x - 1"
+                .parse::<PolynomialFunction>()
+                .unwrap()
+                .discriminant(),
+            None
+        );
+        assert_eq!(
+            "x^4 - 1"
+                .parse::<PolynomialFunction>()
+                .unwrap()
+                .discriminant(),
+            None
+        );
+        assert_eq!(PolynomialFunction::new(Vec::new()).discriminant(), None);
+    }
+
+    #[test]
+    fn vieta() {
+        let func: PolynomialFunction = "x^2 - 5x + 6".parse().unwrap();
+        let roots = func.roots().unwrap();
+
+        assert_eq!(func.sum_of_roots(), Some(Number::new(5, 1, true)));
+        assert_eq!(func.product_of_roots(), Some(Number::new(6, 1, true)));
+        assert_eq!(roots[0] + roots[1], Number::new(5, 1, true));
+        assert_eq!(roots[0] * roots[1], Number::new(6, 1, true));
+
+        // 2(x - 1)(x - 2)(x + 3)
+        let func: PolynomialFunction = "2x^3 - 14x + 12".parse().unwrap();
+        assert_eq!(func.sum_of_roots().map(|r| r.signum()), Some(0));
+        assert_eq!(
+            func.product_of_roots().map(|r| r.to_string()),
+            Some("-6".to_string())
+        );
+    }
+
+    #[test]
+    fn vieta_constant() {
+        let func: PolynomialFunction = "4".parse().unwrap();
+        assert_eq!(func.sum_of_roots(), None);
+        assert_eq!(func.product_of_roots(), None);
+        assert_eq!(PolynomialFunction::new(Vec::new()).sum_of_roots(), None);
+        assert_eq!(PolynomialFunction::new(Vec::new()).product_of_roots(), None);
+    }
+
+    #[test]
+    fn equality() {
+        let func = PolynomialFunction::new(vec![
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(1, 1, true), 0),
+        ]);
+        let reordered = PolynomialFunction::new(vec![
+            Term::new(Number::new(1, 2, true), 0),
+            Term::new(Number::new(1, 1, true), 2),
+            Term::new(Number::new(3, 6, true), 0),
+            Term::new(Number::new(0, 1, true), 1),
+        ]);
+        let different: PolynomialFunction = "x^2 - 1".parse().unwrap();
+
+        assert_eq!(func, reordered);
+        assert_ne!(func, different);
+        assert_eq!(func.clone(), func);
+    }
+
+    #[test]
+    fn iter_terms_agrees_with_coefficient() {
+        let func: PolynomialFunction = "x^7 - 2/3 x^4 + 5x".parse().unwrap();
+
+        for (degree, coefficient) in func.iter_terms() {
+            assert_eq!(func.coefficient(degree), coefficient);
+        }
+        assert_eq!(
+            func.iter_terms().map(|(d, _)| d).collect::<Vec<_>>(),
+            vec![7, 4, 1]
+        );
+        assert_eq!(func.coefficient(3), Number::zero());
+    }
+
+    #[test]
+    fn scale() {
+        let func: PolynomialFunction = "x^2 - 3x + 1/2".parse().unwrap();
+
+        assert_eq!(
+            func.scale(Number::new(2, 1, false)).to_string(),
+            "-2x^2 + 6x - 1"
+        );
+        assert_eq!(
+            func.scale(Number::zero()),
+            PolynomialFunction::new(Vec::new())
+        );
+    }
+
+    #[test]
+    fn interpolate_lagrange() {
+        let points = [
+            (0.into(), 0.into()),
+            (1.into(), 1.into()),
+            (2.into(), 4.into()),
+        ];
+        assert_eq!(
+            PolynomialFunction::interpolate_lagrange(&points),
+            Some("x^2".parse().unwrap())
+        );
+
+        let points = [
+            (Number::new(1, 1, false), 5.into()),
+            (3.into(), Number::new(3, 1, false)),
+        ];
+        assert_eq!(
+            PolynomialFunction::interpolate_lagrange(&points),
+            Some("-2x + 3".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn interpolate_lagrange_duplicate_x() {
+        let points = [(1.into(), 0.into()), (Number::new(1, 1, false), 1.into())];
+        assert!(PolynomialFunction::interpolate_lagrange(&points).is_some());
+
+        let points = [
+            (1.into(), 0.into()),
+            (2.into(), 1.into()),
+            (Number::new(2, 2, true), 4.into()),
+        ];
+        assert_eq!(PolynomialFunction::interpolate_lagrange(&points), None);
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        let original: PolynomialFunction = "x^2 - 3x + 2".parse().unwrap();
+        let mut copy = original.clone();
+        copy.terms[0].coefficient = 5.into();
+        copy.terms.pop();
+
+        assert_eq!(original.to_string(), "x^2 - 3x + 2");
+        assert_eq!(copy.to_string(), "5x^2 - 3x");
+        assert_eq!(original.roots().unwrap(), vec![Number::from(1), 2.into()]);
+    }
+
+    #[test]
+    fn builder() {
+        let built = PolynomialFunction::builder()
+            .term(Number::new(1, 2, true), 3)
+            .term(4.into(), 0)
+            .term(2.into(), 3)
+            .term(Number::new(3, 1, false), 1)
+            .build();
+        let by_hand = PolynomialFunction::new(vec![
+            Term::new(Number::new(5, 2, true), 3),
+            Term::new(Number::new(3, 1, false), 1),
+            Term::new(4.into(), 0),
+        ]);
+        assert_eq!(built, by_hand);
+        assert_eq!(built, "5/2x^3 - 3x + 4".parse().unwrap());
+
+        let mut builder = PolynomialBuilder::new();
+        for degree in 0..4 {
+            if degree % 2 == 1 {
+                builder = builder.term(degree.into(), degree);
+            }
+        }
+        assert_eq!(builder.build(), "3x^3 + x".parse().unwrap());
+        assert_eq!(
+            PolynomialBuilder::new()
+                .term(1.into(), 2)
+                .term(Number::new(1, 1, false), 2)
+                .build(),
+            PolynomialFunction::new(Vec::new())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn root_bound() {
+        let cases: [(&str, &[f64]); 4] = [
+            ("x^2 - 3x + 2", &[1.0, 2.0]),
+            ("2x^3 - 3x^2 - 11x + 6", &[-2.0, 0.5, 3.0]),
+            ("x^4 - 100", &[10f64.sqrt(), -(10f64.sqrt())]),
+            ("x - 1/1000", &[0.001]),
+        ];
+        for (text, roots) in cases {
+            let func: PolynomialFunction = text.parse().unwrap();
+            let bound = func.root_bound();
+            assert!(roots.iter().all(|r| r.abs() < bound), "{text}");
+            assert!(func
+                .roots_numeric(1e-9, 200)
+                .iter()
+                .all(|r| r.abs() < bound));
+        }
+
+        // x^2 + 4 has roots +-2i
+        assert!(
+            PolynomialFunction::from_coeffs(&[4.into(), 0.into(), 1.into()]).root_bound() > 2.0
+        );
+        assert_eq!(PolynomialFunction::new(Vec::new()).root_bound(), 0.0);
+    }
+
+    #[test]
+    fn reverse() {
+        let func: PolynomialFunction = "2x^2 + 3x + 4".parse().unwrap();
+        assert_eq!(func.reverse(), "4x^2 + 3x + 2".parse().unwrap());
+        assert_eq!(func.reverse().reverse(), func);
+
+        let func: PolynomialFunction = "2x^2 + 3x + 5".parse().unwrap();
+        assert_eq!(func.reverse(), "5x^2 + 3x + 2".parse().unwrap());
+        let func: PolynomialFunction = "x^5 - 1/2x^3 + 7".parse().unwrap();
+        assert_eq!(func.reverse().reverse(), func);
+
+        let func = PolynomialFunction::from_roots(&[2.into(), Number::new(1, 3, false)]);
+        for root in [(-3).into(), Number::new(1, 2, true)] {
+            assert_eq!(func.reverse().evaluate_exact(root), Number::from(0));
+        }
+
+        // the factor of x is lost
+        let func: PolynomialFunction = "x^3 - 2x^2".parse().unwrap();
+        assert_eq!(func.reverse(), "-2x + 1".parse().unwrap());
+        assert_eq!(func.reverse().reverse(), "x - 2".parse().unwrap());
+        assert_eq!(
+            PolynomialFunction::new(Vec::new()).reverse(),
+            PolynomialFunction::new(Vec::new())
+        );
+    }
+
+    #[test]
+    fn map_coefficients() {
+        let func: PolynomialFunction = "3x^3 - 1/2x + 4".parse().unwrap();
+        assert_eq!(func.map_coefficients(|c| -c), -func.clone());
+        assert_eq!(
+            func.map_coefficients(|_| Number::zero()),
+            PolynomialFunction::new(Vec::new())
+        );
+
+        // only the non-integer coefficient maps to zero
+        let mut seen = 0;
+        let integers = func.map_coefficients(|c| {
+            seen += 1;
+            if c.is_integer() {
+                c
+            } else {
+                Number::zero()
+            }
+        });
+        assert_eq!(seen, 3);
+        assert_eq!(integers, "3x^3 + 4".parse().unwrap());
+    }
+
+    #[test]
+    fn palindromic() {
+        let func: PolynomialFunction = "x^4 + 3x^3 + 5x^2 + 3x + 1".parse().unwrap();
+        assert!(func.is_palindromic());
+        assert!(!func.is_anti_palindromic());
+        assert_eq!(func.reverse(), func);
+
+        let func: PolynomialFunction = "x^4 + 3x^3 + 5x^2 + 2x + 1".parse().unwrap();
+        assert!(!func.is_palindromic());
+        // a zero constant term breaks the symmetry
+        assert!(!"x^3 + x^2"
+            .parse::<PolynomialFunction>()
+            .unwrap()
+            .is_palindromic());
+
+        let func: PolynomialFunction = "2x^3 + 1/2x^2 - 1/2x - 2".parse().unwrap();
+        assert!(func.is_anti_palindromic());
+        assert!(!func.is_palindromic());
+        assert!("x^2 - 1"
+            .parse::<PolynomialFunction>()
+            .unwrap()
+            .is_anti_palindromic());
+    }
+
+    #[test]
+    fn modulo() {
+        let func: PolynomialFunction = "x^2 + 5x + 6".parse().unwrap();
+        assert_eq!(func.modulo(2), Ok("x^2 + x".parse().unwrap()));
+        assert_eq!(func.modulo(3), Ok("x^2 + 2x".parse().unwrap()));
+        assert_eq!(
+            "-x^3 - 7".parse::<PolynomialFunction>().unwrap().modulo(5),
+            Ok("4x^3 + 3".parse().unwrap())
+        );
+        assert_eq!(
+            "2x^2 + 4".parse::<PolynomialFunction>().unwrap().modulo(2),
+            Ok(PolynomialFunction::new(Vec::new()))
+        );
+        assert_eq!(
+            "x^2 + 1/2x"
+                .parse::<PolynomialFunction>()
+                .unwrap()
+                .modulo(7),
+            Err(PolysolveError::NotInteger)
+        );
+        assert_eq!(func.modulo(0), Err(PolysolveError::DivisionByZero));
+    }
+
+    #[test]
+    fn constant() {
+        assert_eq!(PolynomialFunction::constant(Number::zero()).degree(), None);
+        assert_eq!(
+            PolynomialFunction::constant(Number::zero()),
+            PolynomialFunction::zero()
+        );
+        assert_eq!(
+            PolynomialFunction::zero(),
+            PolynomialFunction::new(Vec::new())
+        );
+
+        let five = PolynomialFunction::constant(5.into());
+        assert_eq!(five.degree(), Some(0));
+        assert_eq!(five.to_string(), "5");
+        for x in [(-3).into(), Number::zero(), Number::new(7, 2, true)] {
+            assert_eq!(five.evaluate_exact(x), Number::from(5));
+        }
+        #[cfg(feature = "std")]
+        assert_eq!(five.evaluate(1e6), 5.0);
+    }
+
+    #[test]
+    fn pow() {
+        let func: PolynomialFunction = "x + 1".parse().unwrap();
+        assert_eq!(func.pow(0), PolynomialFunction::constant(1.into()));
+        assert_eq!(func.pow(1), func);
+        assert_eq!(func.pow(3), "x^3 + 3x^2 + 3x + 1".parse().unwrap());
+        assert_eq!(
+            func.pow(6).coefficients(),
+            [1, 6, 15, 20, 15, 6, 1].map(Number::from).to_vec()
+        );
+
+        let func: PolynomialFunction = "1/2x^2 - 1".parse().unwrap();
+        assert_eq!(
+            func.pow(5),
+            (0..5).fold(PolynomialFunction::constant(1.into()), |acc, _| &acc
+                * &func)
+        );
+        assert_eq!(
+            PolynomialFunction::zero().pow(0),
+            PolynomialFunction::constant(1.into())
+        );
+        assert_eq!(
+            PolynomialFunction::zero().pow(2),
+            PolynomialFunction::zero()
+        );
+    }
 }
@@ -1,96 +1,635 @@
-use std::ops::{Add, AddAssign, Div, Mul};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+use core::hash::Hash;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub};
 
-fn gcd(a: u32, b: u32) -> u32 {
-    if b == 0 {
-        return a;
+use crate::error::PolysolveError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberError {
+    ZeroDenominator,
+    DivisionByZero,
+    Invalid,
+}
+
+impl fmt::Display for NumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumberError::ZeroDenominator => write!(f, "denominator is zero"),
+            NumberError::DivisionByZero => write!(f, "division by zero"),
+            NumberError::Invalid => write!(f, "invalid number"),
+        }
+    }
+}
+
+impl Error for NumberError {}
+
+/// The integer arithmetic a [`Rational`] is built on. Operations panic rather
+/// than wrap when the result doesn't fit.
+pub trait Backing: Clone + Ord + Hash + fmt::Debug + fmt::Display {
+    fn from_i64(value: i64) -> Self;
+    fn add(&self, rhs: &Self) -> Self;
+    fn sub(&self, rhs: &Self) -> Self;
+    fn mul(&self, rhs: &Self) -> Self;
+    /// Division rounding toward zero.
+    fn div(&self, rhs: &Self) -> Self;
+    /// Remainder of [`Backing::div`], with the sign of `self`.
+    fn rem(&self, rhs: &Self) -> Self;
+    fn to_f64(&self) -> f64;
+
+    fn zero() -> Self {
+        Self::from_i64(0)
+    }
+
+    fn one() -> Self {
+        Self::from_i64(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        *self < Self::zero()
+    }
+
+    fn neg(&self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    fn abs(&self) -> Self {
+        if self.is_negative() {
+            self.neg()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Never negative; `gcd(0, 0)` is zero.
+    fn gcd(&self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (self.abs(), rhs.abs());
+        while !b.is_zero() {
+            (a, b) = (b.clone(), a.rem(&b));
+        }
+        a
+    }
+
+    /// The positive divisors of a nonzero `self`, in no particular order. Trial
+    /// division up to the square root unless the backing knows better.
+    fn divisors(&self) -> Vec<Self> {
+        trial_divisors(self)
+    }
+}
+
+fn trial_divisors<I: Backing>(n: &I) -> Vec<I> {
+    let n = n.abs();
+    let mut out = Vec::new();
+    // divisors come in pairs around the square root
+    let mut x = I::one();
+    while x <= n.div(&x) {
+        if n.rem(&x).is_zero() {
+            let pair = n.div(&x);
+            if pair != x {
+                out.push(pair);
+            }
+            out.push(x.clone());
+        }
+        x = x.add(&I::one());
+    }
+    out
+}
+
+macro_rules! primitive_backing {
+    ($($t:ty),*) => {$(
+        impl Backing for $t {
+            fn from_i64(value: i64) -> Self {
+                value as $t
+            }
+
+            fn add(&self, rhs: &Self) -> Self {
+                self.checked_add(*rhs).expect("Number overflow")
+            }
+
+            fn sub(&self, rhs: &Self) -> Self {
+                self.checked_sub(*rhs).expect("Number overflow")
+            }
+
+            fn mul(&self, rhs: &Self) -> Self {
+                self.checked_mul(*rhs).expect("Number overflow")
+            }
+
+            fn div(&self, rhs: &Self) -> Self {
+                self.checked_div(*rhs).expect("Number overflow")
+            }
+
+            fn rem(&self, rhs: &Self) -> Self {
+                self.checked_rem(*rhs).expect("Number overflow")
+            }
+
+            fn to_f64(&self) -> f64 {
+                *self as f64
+            }
+
+            // trial division up to 2^32 would take seconds on a large prime
+            fn divisors(&self) -> Vec<Self> {
+                match u64::try_from(u128::from(self.unsigned_abs())) {
+                    Ok(n) => u64_divisors(n).into_iter().map(|d| d as $t).collect(),
+                    Err(_) => trial_divisors(self),
+                }
+            }
+        }
+    )*};
+}
+
+primitive_backing!(i64, i128);
+
+// Every divisor of a nonzero n, from its prime factorization
+fn u64_divisors(n: u64) -> Vec<u64> {
+    let mut primes = Vec::new();
+    factorize(n, &mut primes);
+    primes.sort_unstable();
+
+    let mut out = vec![1];
+    let mut i = 0;
+    while i < primes.len() {
+        let p = primes[i];
+        let power = primes[i..].iter().take_while(|&&q| q == p).count();
+        let previous = out.len();
+        let mut factor = 1;
+        for _ in 0..power {
+            factor *= p;
+            for j in 0..previous {
+                out.push(out[j] * factor);
+            }
+        }
+        i += power;
+    }
+    out
+}
+
+fn factorize(n: u64, primes: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        primes.push(n);
+        return;
+    }
+    let d = if n.is_multiple_of(2) {
+        2
     } else {
-        return gcd(b, a % b);
+        pollard_rho(n)
+    };
+    factorize(d, primes);
+    factorize(n / d, primes);
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut out = 1;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            out = mul_mod(out, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
     }
+    out
 }
 
-#[derive(Debug, Clone, Copy, Hash)]
-pub struct Number {
-    pub numerator: u32,
-    pub denominator: u32,
-    positive: bool,
+// Miller-Rabin, deterministic for every u64 with these bases
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(&p) = BASES.iter().find(|&&p| n.is_multiple_of(p)) {
+        return n == p;
+    }
+    let (mut d, mut s) = (n - 1, 0);
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    BASES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
 }
 
-impl Number {
-    pub fn new(numerator: u32, denominator: u32, sign: bool) -> Self {
+// A nontrivial factor of an odd composite n
+fn pollard_rho(n: u64) -> u64 {
+    let gcd = |mut a: u64, mut b: u64| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    for c in 1..n {
+        let step = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let (mut slow, mut fast, mut d) = (2, 2, 1);
+        while d == 1 {
+            slow = step(slow);
+            fast = step(step(fast));
+            d = gcd(slow.abs_diff(fast), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!("Pollard's rho always finds a factor of a composite")
+}
+
+/// A fraction over any [`Backing`] integer, kept reduced with a positive
+/// denominator so that derived equality and hashing compare values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<I> {
+    numerator: I,
+    denominator: I,
+}
+
+/// The crate's default rational, backed by `i64`.
+pub type Number = Rational<i64>;
+
+impl<I: Backing> Rational<I> {
+    /// `numerator / denominator` from signed parts. Panics if `denominator` is zero.
+    pub fn ratio(numerator: I, denominator: I) -> Self {
+        assert!(!denominator.is_zero(), "Number with a zero denominator");
         let mut out = Self {
             numerator,
             denominator,
-            positive: sign,
         };
         out.simplify();
         out
     }
 
+    /// The numerator, which carries the sign.
+    pub fn numer(&self) -> &I {
+        &self.numerator
+    }
+
+    /// The denominator, always positive.
+    pub fn denom(&self) -> &I {
+        &self.denominator
+    }
+
+    pub fn zero() -> Self {
+        Self::ratio(I::zero(), I::one())
+    }
+
+    pub fn one() -> Self {
+        Self::ratio(I::one(), I::one())
+    }
+
     pub fn simplify(&mut self) {
-        let fac = gcd(self.numerator, self.denominator);
-        self.numerator /= fac;
-        self.denominator /= fac;
+        // a single canonical zero keeps Hash consistent with Eq
+        if self.numerator.is_zero() {
+            self.denominator = I::one();
+            return;
+        }
+        let mut fac = self.numerator.gcd(&self.denominator);
+        if self.denominator.is_negative() {
+            fac = fac.neg();
+        }
+        self.numerator = self.numerator.div(&fac);
+        self.denominator = self.denominator.div(&fac);
     }
 
     pub fn not_zero(&self) -> bool {
-        self.numerator != 0
+        !self.numerator.is_zero()
+    }
+
+    /// The largest rational that divides both into integers: `gcd(a, c) / lcm(b, d)`
+    /// for `a/b` and `c/d`. Never negative.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (b, d) = (&self.denominator, &other.denominator);
+        Self::ratio(
+            self.numerator.gcd(&other.numerator),
+            b.div(&b.gcd(d)).mul(d),
+        )
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numerator.to_f64() / self.denominator.to_f64()
+    }
+
+    /// Whether the `f64` values of `self` and `other` are within `tol`.
+    pub fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        super::approx_eq(self.to_f64(), other.to_f64(), tol)
+    }
+
+    pub fn reciprocal(&self) -> Result<Self, NumberError> {
+        if self.numerator.is_zero() {
+            return Err(NumberError::DivisionByZero);
+        }
+        Ok(Self::ratio(
+            self.denominator.clone(),
+            self.numerator.clone(),
+        ))
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::ratio(self.numerator.abs(), self.denominator.clone())
+    }
+
+    /// `lo` below the range, `hi` above it, `self` inside. Panics if `lo > hi`, as
+    /// [`Ord::clamp`] does.
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        assert!(lo <= hi, "clamp range {} > {}", lo, hi);
+        Ord::clamp(self, lo, hi)
+    }
+
+    pub fn signum(&self) -> i32 {
+        match self.numerator.cmp(&I::zero()) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+
+    /// Zero is neither positive nor negative.
+    pub fn is_positive(&self) -> bool {
+        self.signum() == 1
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.signum() == -1
     }
 
-    pub fn factors(&self) -> Vec<i32> {
-        (1..=self.numerator / 2)
-            .filter(|x| self.numerator % x == 0)
-            .flat_map(|x| [x as i32, -(x as i32)])
-            .chain([self.numerator as i32, -(self.numerator as i32)])
+    /// Every divisor of the numerator, positive and negative.
+    pub fn factors(&self) -> Vec<I> {
+        if self.numerator.is_zero() {
+            return vec![I::zero(), I::zero()];
+        }
+        self.numerator
+            .divisors()
+            .into_iter()
+            .flat_map(|d| [d.neg(), d])
             .collect()
     }
 
     pub fn is_integer(&self) -> bool {
-        self.denominator == 1
+        self.denominator == I::one()
+    }
+
+    pub fn floor(&self) -> Self {
+        let quotient = self.numerator.div(&self.denominator);
+        if self.numerator.is_negative() && !self.is_integer() {
+            Self::ratio(quotient.sub(&I::one()), I::one())
+        } else {
+            Self::ratio(quotient, I::one())
+        }
+    }
+
+    pub fn ceil(&self) -> Self {
+        -(-self.clone()).floor()
+    }
+
+    /// Rounds to the nearest integer, with halves rounded away from zero.
+    pub fn round(&self) -> Self {
+        let magnitude = self.numerator.abs();
+        let mut quotient = magnitude.div(&self.denominator);
+        let remainder = magnitude.rem(&self.denominator);
+        if remainder >= self.denominator.sub(&remainder) {
+            quotient = quotient.add(&I::one());
+        }
+        if self.numerator.is_negative() {
+            quotient = quotient.neg();
+        }
+        Self::ratio(quotient, I::one())
+    }
+
+    /// `self^exp` by repeated squaring; a negative `exp` raises the reciprocal.
+    /// Panics on overflow, and on a negative power of zero.
+    pub fn pow(&self, exp: i32) -> Self {
+        let mut base = if exp < 0 {
+            Self::one() / self.clone()
+        } else {
+            self.clone()
+        };
+        let mut exp = exp.unsigned_abs();
+        let mut out = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out *= base.clone();
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        out
+    }
+}
+
+impl Number {
+    /// Panics if `denominator` is zero; see [`Number::try_new`].
+    pub fn new(numerator: u32, denominator: u32, sign: bool) -> Self {
+        Self::try_new(numerator, denominator, sign).expect("Number with a zero denominator")
+    }
+
+    pub fn try_new(numerator: u32, denominator: u32, sign: bool) -> Result<Self, NumberError> {
+        if denominator == 0 {
+            return Err(NumberError::ZeroDenominator);
+        }
+        let numerator = numerator as i64;
+        Ok(Self::ratio(
+            if sign { numerator } else { -numerator },
+            denominator as i64,
+        ))
+    }
+
+    /// [`Rational::ratio`], failing with [`PolysolveError::DivisionByZero`] for a zero
+    /// denominator and with [`PolysolveError::Overflow`] where the simplified
+    /// numerator is `i64::MIN`, instead of panicking.
+    pub fn checked_ratio(numerator: i64, denominator: i64) -> Result<Number, PolysolveError> {
+        narrow(numerator.into(), denominator.into())
+    }
+
+    /// `self + rhs`, or [`PolysolveError::Overflow`] where `+` would panic.
+    pub fn checked_add(self, rhs: Number) -> Result<Number, PolysolveError> {
+        let [a, b, c, d] = self.widen(rhs);
+        narrow(a * d + c * b, b * d)
+    }
+
+    pub fn checked_sub(self, rhs: Number) -> Result<Number, PolysolveError> {
+        let [a, b, c, d] = self.widen(rhs);
+        narrow(a * d - c * b, b * d)
     }
 
-    pub fn pow(&self, degree: i32) -> Number {
-        let mut numerator = 1;
-        let mut denominator = 1;
-        for _ in 0..degree {
-            numerator *= self.numerator;
-            denominator *= self.denominator
+    pub fn checked_mul(self, rhs: Number) -> Result<Number, PolysolveError> {
+        let [a, b, c, d] = self.widen(rhs);
+        narrow(a * c, b * d)
+    }
+
+    /// Also fails with [`PolysolveError::DivisionByZero`] if `rhs` is zero.
+    pub fn checked_div(self, rhs: Number) -> Result<Number, PolysolveError> {
+        let [a, b, c, d] = self.widen(rhs);
+        narrow(a * d, b * c)
+    }
+
+    /// [`Rational::pow`], failing with [`PolysolveError::Overflow`] instead of panicking.
+    pub fn checked_pow(self, exp: i32) -> Result<Number, PolysolveError> {
+        let mut base = if exp < 0 {
+            Number::one().checked_div(self)?
+        } else {
+            self
+        };
+        let mut exp = exp.unsigned_abs();
+        let mut out = Number::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out = out.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Ok(out)
+    }
+
+    // products of two i64s always fit in an i128, and so do sums of two such products
+    fn widen(self, rhs: Number) -> [i128; 4] {
+        [
+            self.numerator,
+            self.denominator,
+            rhs.numerator,
+            rhs.denominator,
+        ]
+        .map(i128::from)
+    }
+
+    /// The closest fraction to `value` with a denominator of at most `max_denominator`,
+    /// found from the continued-fraction convergents of `value`. Fails with
+    /// [`PolysolveError::NotFinite`] for NaN and infinities, and with
+    /// [`PolysolveError::Overflow`] for magnitudes past `i64::MAX`.
+    #[cfg(feature = "std")]
+    pub fn from_f64_approx(value: f64, max_denominator: i64) -> Result<Number, PolysolveError> {
+        if !value.is_finite() {
+            return Err(PolysolveError::NotFinite);
+        }
+        let x = value.abs();
+        if !in_i64_range(x) {
+            return Err(PolysolveError::Overflow);
+        }
+        let limit = i64::MAX as u128;
+        let max_denominator = max_denominator.max(1) as u128;
+
+        // the two previous convergents, seeded with 0/1 and 1/0
+        let (mut p0, mut q0, mut p1, mut q1) = (0_u128, 1_u128, 1_u128, 0_u128);
+        let mut remainder = x;
+
+        loop {
+            let a = remainder.floor().min(limit as f64) as u128;
+            let (p2, q2) = (p0 + a * p1, q0 + a * q1);
+
+            if q2 > max_denominator || p2 > limit {
+                // the best semiconvergent still inside the bound may beat p1/q1
+                let k = ((max_denominator - q0) / q1).min((limit - p0) / p1.max(1));
+                let (p, q) = (p0 + k * p1, q0 + k * q1);
+                if (x - p as f64 / q as f64).abs() < (x - p1 as f64 / q1 as f64).abs() {
+                    (p1, q1) = (p, q);
+                }
+                break;
+            }
+
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+
+            let fraction = remainder - a as f64;
+            if fraction <= f64::EPSILON * remainder.max(1.0) {
+                break;
+            }
+            remainder = 1.0 / fraction;
         }
-        Number::new(
+
+        let numerator = p1 as i64;
+        Ok(Number::ratio(
+            if value.is_sign_negative() {
+                -numerator
+            } else {
+                numerator
+            },
+            q1 as i64,
+        ))
+    }
+}
+
+// whether a float's integer part fits an i64; false for NaN
+#[cfg(feature = "std")]
+fn in_i64_range(magnitude: f64) -> bool {
+    // i64::MAX as f64 rounds up to 2^63, which is already out of range
+    magnitude < i64::MAX as f64
+}
+
+fn narrow(numerator: i128, denominator: i128) -> Result<Number, PolysolveError> {
+    if denominator == 0 {
+        return Err(PolysolveError::DivisionByZero);
+    }
+    let wide = Rational::ratio(numerator, denominator);
+    // i64::MIN is out too, since it has no absolute value in i64
+    match (
+        i64::try_from(wide.numerator),
+        i64::try_from(wide.denominator),
+    ) {
+        (Ok(numerator), Ok(denominator)) if numerator != i64::MIN => Ok(Number {
             numerator,
             denominator,
-            if degree % 2 == 0 { true } else { self.positive },
+        }),
+        _ => Err(PolysolveError::Overflow),
+    }
+}
+
+impl<I: Backing> Add for Rational<I> {
+    type Output = Self;
+
+    // over the gcd of the denominators, so the intermediates stay small
+    fn add(self, rhs: Self) -> Self::Output {
+        let g = self.denominator.gcd(&rhs.denominator);
+        let (b, d) = (self.denominator.div(&g), rhs.denominator.div(&g));
+        Self::ratio(
+            self.numerator.mul(&d).add(&rhs.numerator.mul(&b)),
+            b.mul(&d).mul(&g),
         )
     }
 }
 
-impl Add<Number> for Number {
-    type Output = Number;
-    fn add(self, rhs: Number) -> Self::Output {
-        let lhs_factor = if self.positive { 1 } else { -1 };
-        let rhs_factor = if rhs.positive { 1 } else { -1 };
-        let numerator = ((self.numerator * rhs.denominator) as i32 * lhs_factor)
-            + ((rhs.numerator * self.denominator) as i32 * rhs_factor);
-        let denom = self.denominator * rhs.denominator;
-        Number::new(numerator.abs() as u32, denom, numerator.is_positive())
+impl<I: Backing> Sub for Rational<I> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
     }
 }
 
 impl Add<f64> for Number {
     type Output = f64;
+
     fn add(self, rhs: f64) -> Self::Output {
-        self.add(Into::<Number>::into(rhs)).into()
+        self.to_f64() + rhs
     }
 }
 
-impl AddAssign for Number {
+impl<I: Backing> AddAssign for Rational<I> {
     fn add_assign(&mut self, rhs: Self) {
-        let lhs_factor = if self.positive { 1 } else { -1 };
-        let rhs_factor = if rhs.positive { 1 } else { -1 };
-        let numerator = ((self.numerator * rhs.denominator) as i32 * lhs_factor)
-            + ((rhs.numerator * self.denominator) as i32 * rhs_factor);
-        let denom = self.denominator * rhs.denominator;
-        let number = Number::new(numerator.abs() as u32, denom, numerator.is_positive());
-        *self = number;
+        *self = self.clone() + rhs;
     }
 }
 
@@ -98,7 +637,7 @@ impl Mul<f64> for Number {
     type Output = f64;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        (self.numerator as f64 * rhs) / self.denominator as f64
+        self.to_f64() * rhs
     }
 }
 
@@ -106,7 +645,15 @@ impl Mul<u32> for Number {
     type Output = Number;
 
     fn mul(self, rhs: u32) -> Self::Output {
-        Self::new(self.numerator * rhs, self.denominator, self.positive)
+        self * Number::from(rhs)
+    }
+}
+
+impl Mul<i32> for Number {
+    type Output = Number;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        self * Number::from(rhs)
     }
 }
 
@@ -114,7 +661,7 @@ impl Div<f64> for Number {
     type Output = f64;
 
     fn div(self, rhs: f64) -> Self::Output {
-        (self.numerator as f64) / (self.denominator as f64 * rhs)
+        self.to_f64() / rhs
     }
 }
 
@@ -122,106 +669,781 @@ impl Div<u32> for Number {
     type Output = Number;
 
     fn div(self, rhs: u32) -> Self::Output {
-        Self::new(self.numerator, self.denominator * rhs, self.positive)
+        self / Number::from(rhs)
     }
 }
 
-impl Mul<Number> for Number {
+/// Panics if `rhs` is zero, like division by a zero `Number`.
+impl Div<i32> for Number {
     type Output = Number;
 
-    fn mul(self, rhs: Number) -> Self::Output {
-        let sign = match self.positive {
-            true => rhs.positive,
-            false => !rhs.positive,
-        };
-        Self::new(
-            self.numerator * rhs.numerator,
-            self.denominator * rhs.denominator,
-            sign,
-        )
+    fn div(self, rhs: i32) -> Self::Output {
+        self / Number::from(rhs)
     }
 }
 
-impl Div<Number> for Number {
-    type Output = Number;
+impl<I: Backing> Mul for Rational<I> {
+    type Output = Self;
 
-    fn div(self, rhs: Number) -> Self::Output {
-        Self::new(
-            self.numerator * rhs.denominator,
-            self.denominator * rhs.numerator,
-            self.positive ^ rhs.positive,
+    // cancelling across first leaves the product already reduced
+    fn mul(self, rhs: Self) -> Self::Output {
+        let g1 = self.numerator.gcd(&rhs.denominator);
+        let g2 = rhs.numerator.gcd(&self.denominator);
+        Self::ratio(
+            self.numerator.div(&g1).mul(&rhs.numerator.div(&g2)),
+            self.denominator.div(&g2).mul(&rhs.denominator.div(&g1)),
         )
     }
 }
 
-impl PartialEq for Number {
-    fn eq(&self, other: &Self) -> bool {
-        self.numerator * other.denominator == other.numerator * self.denominator
+impl<I: Backing> Div for Rational<I> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(rhs.not_zero(), "Number with a zero denominator");
+        self * Self {
+            numerator: rhs.denominator,
+            denominator: rhs.numerator,
+        }
+    }
+}
+
+// remainder of truncating division, so it takes the sign of `self`
+impl<I: Backing> Rem for Rational<I> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = self.clone() / rhs.clone();
+        let truncated = Self::ratio(quotient.numerator.div(&quotient.denominator), I::one());
+        self - rhs * truncated
+    }
+}
+
+impl<I: Backing> MulAssign for Rational<I> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<I: Backing> DivAssign for Rational<I> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+impl<I: Backing> Neg for Rational<I> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: self.numerator.neg(),
+            denominator: self.denominator,
+        }
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Number {
+    fn zero() -> Self {
+        Number::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.not_zero()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::One for Number {
+    fn one() -> Self {
+        Number::one()
     }
 }
 
-impl Eq for Number {}
+/// Parses `a` or `a/b`, each part an integer in `radix`. A fraction that
+/// simplifies to a numerator of `i64::MIN` is [`NumberError::Invalid`], like any
+/// other part out of range.
+#[cfg(feature = "num-traits")]
+impl num_traits::Num for Number {
+    type FromStrRadixErr = NumberError;
+
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        let (numerator, denominator) = text.split_once('/').unwrap_or((text, "1"));
+        let numerator = i64::from_str_radix(numerator, radix).map_err(|_| NumberError::Invalid)?;
+        let denominator =
+            i64::from_str_radix(denominator, radix).map_err(|_| NumberError::Invalid)?;
+        if denominator == 0 {
+            return Err(NumberError::ZeroDenominator);
+        }
+        Number::checked_ratio(numerator, denominator).map_err(|_| NumberError::Invalid)
+    }
+}
 
-impl PartialOrd for Number {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some((self.numerator * other.denominator).cmp(&(other.numerator * self.denominator)))
+impl<I: Backing> PartialOrd for Rational<I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl Ord for Number {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+impl<I: Backing> Ord for Rational<I> {
+    // compares continued-fraction expansions instead of cross-multiplying,
+    // since the cross products can overflow where the fractions don't
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.signum(), other.signum()) {
+            (lhs, rhs) if lhs != rhs => return lhs.cmp(&rhs),
+            (0, _) => return Ordering::Equal,
+            (-1, _) => return (-other.clone()).cmp(&-self.clone()),
+            _ => {}
+        }
+
+        let (mut a, mut b) = (self.numerator.clone(), self.denominator.clone());
+        let (mut c, mut d) = (other.numerator.clone(), other.denominator.clone());
+        let mut flipped = false;
+        loop {
+            let finish = |order: Ordering| if flipped { order.reverse() } else { order };
+            let (q1, q2) = (a.div(&b), c.div(&d));
+            if q1 != q2 {
+                return finish(q1.cmp(&q2));
+            }
+            let (r1, r2) = (a.rem(&b), c.rem(&d));
+            match (r1.is_zero(), r2.is_zero()) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => return finish(Ordering::Less),
+                (false, true) => return finish(Ordering::Greater),
+                (false, false) => {}
+            }
+            // with equal integer parts, a/b vs c/d orders like d/r2 vs b/r1
+            (a, b, c, d) = (b, r1, d, r2);
+            flipped = !flipped;
+        }
     }
 }
 
 impl From<u32> for Number {
     fn from(value: u32) -> Self {
-        Self::new(value, 1, true)
+        Self::ratio(value as i64, 1)
+    }
+}
+
+impl<I: Backing> From<i32> for Rational<I> {
+    fn from(value: i32) -> Self {
+        Self::ratio(I::from_i64(value as i64), I::one())
+    }
+}
+
+impl<I: Backing> From<i64> for Rational<I> {
+    fn from(value: i64) -> Self {
+        Self::ratio(I::from_i64(value), I::one())
+    }
+}
+
+/// Builds `numerator / denominator`; panics if the denominator is zero.
+impl<I: Backing> From<(i32, i32)> for Rational<I> {
+    fn from((numerator, denominator): (i32, i32)) -> Self {
+        Self::ratio(
+            I::from_i64(numerator as i64),
+            I::from_i64(denominator as i64),
+        )
     }
 }
 
-impl Into<f64> for Number {
-    fn into(self) -> f64 {
-        self.numerator as f64 / self.denominator as f64
+impl<I: Backing> From<Rational<I>> for f64 {
+    fn from(value: Rational<I>) -> Self {
+        value.to_f64()
     }
 }
 
-impl From<f64> for Number {
-    fn from(value: f64) -> Self {
-        let mut i = 0;
-        let mut new_numerator = value;
+/// Fails with [`PolysolveError::NotFinite`] for NaN and infinities, and with
+/// [`PolysolveError::Overflow`] for magnitudes past `i64::MAX`.
+#[cfg(feature = "std")]
+impl TryFrom<f64> for Number {
+    type Error = PolysolveError;
+
+    // Scales by powers of ten until the value is integral to within rounding error,
+    // stopping short of overflowing i64. Values that never terminate (like 1/3)
+    // come out as the closest fraction over the largest power of ten reached.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(PolysolveError::NotFinite);
+        }
+        let magnitude = value.abs();
+        if !in_i64_range(magnitude) {
+            return Err(PolysolveError::Overflow);
+        }
+        let mut denominator: i64 = 1;
 
         loop {
-            if (new_numerator.floor() - new_numerator).abs() < f64::EPSILON {
+            let scaled = magnitude * denominator as f64;
+            let tolerance = 4.0 * f64::EPSILON * scaled.max(1.0);
+            if (scaled - scaled.round()).abs() <= tolerance
+                || denominator > i64::MAX / 10
+                || !in_i64_range(scaled * 10.0)
+            {
                 break;
             }
-            i += 1;
-            new_numerator = value * 10_f64.powi(i);
+            denominator *= 10;
         }
 
-        Self::new(
-            new_numerator.floor().abs() as u32,
-            (10 as u32).pow(i as u32),
-            value.is_sign_positive(),
-        )
+        let numerator = (magnitude * denominator as f64).round() as i64;
+        Ok(Self::ratio(
+            if value.is_sign_negative() {
+                -numerator
+            } else {
+                numerator
+            },
+            denominator,
+        ))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn test_simplify() {
-        assert_eq!(Number::new(9, 3, true), Number::new(3, 1, true));
-        assert_eq!(Number::new(8, 16, true), Number::new(1, 2, true));
-        assert_eq!(Number::new(153, 3, true), Number::new(51, 1, true));
-    }
+/// Compares exactly: every finite float is a fraction over a power of two, so no
+/// float is equal to `1/3` and `0.1` is slightly more than `1/10`. NaN is unordered.
+impl PartialOrd<f64> for Number {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        if other.is_nan() {
+            return None;
+        }
+        if other.is_infinite() {
+            return Some(if *other > 0.0 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            });
+        }
 
-    #[test]
-    fn test_division() {}
+        // other = ±mantissa * 2^exponent, with subnormals sharing the lowest exponent
+        let bits = other.to_bits();
+        let biased = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as u128;
+        let (mantissa, exponent) = if biased == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased - 1075)
+        };
 
-    #[test]
-    fn test_multiplication() {
+        let sign = |negative: bool, zero: bool| match (zero, negative) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        let own = sign(self.numerator < 0, self.numerator == 0);
+        let theirs = sign(other.is_sign_negative(), mantissa == 0);
+        if own != theirs || own == 0 {
+            return Some(own.cmp(&theirs));
+        }
+
+        // both sides are now nonzero with the same sign
+        let magnitude = cmp_with_power_of_two(
+            self.numerator.unsigned_abs() as u128,
+            self.denominator as u128,
+            mantissa,
+            exponent,
+        );
+        Some(if own < 0 {
+            magnitude.reverse()
+        } else {
+            magnitude
+        })
+    }
+}
+
+/// Equal exactly when [`PartialOrd<f64>`] says so.
+impl PartialEq<f64> for Number {
+    fn eq(&self, other: &f64) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+// compares n / d with m * 2^e for n, d below 2^63 and m below 2^53
+fn cmp_with_power_of_two(n: u128, d: u128, m: u128, e: i32) -> Ordering {
+    if e >= 0 {
+        // past 2^64 the float already outgrows any n / d
+        if 128 - m.leading_zeros() as i32 + e > 64 {
+            return Ordering::Less;
+        }
+        n.cmp(&((m << e) * d))
+    } else {
+        // n * 2^-e against m * d, which stays below 2^116
+        let shift = -e as u32;
+        if shift >= n.leading_zeros() {
+            return Ordering::Greater;
+        }
+        (n << shift).cmp(&(m * d))
+    }
+}
+
+impl<I: Backing> fmt::Display for Rational<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_simplify() {
+        assert_eq!(Number::new(9, 3, true), Number::new(3, 1, true));
+        assert_eq!(Number::new(8, 16, true), Number::new(1, 2, true));
+        assert_eq!(Number::new(153, 3, true), Number::new(51, 1, true));
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Number::try_new(6, 8, false), Ok(Number::new(3, 4, false)));
+        assert_eq!(Number::try_new(0, 5, true), Ok(Number::zero()));
+        assert_eq!(
+            Number::try_new(1, 0, true),
+            Err(NumberError::ZeroDenominator)
+        );
+        assert_eq!(
+            NumberError::ZeroDenominator.to_string(),
+            "denominator is zero"
+        );
+
+        for (numerator, denominator) in [(0, 1), (7, 3), (12, 18), (u32::MAX, u32::MAX - 1)] {
+            for sign in [true, false] {
+                assert_eq!(
+                    Number::try_new(numerator, denominator, sign),
+                    Ok(Number::new(numerator, denominator, sign))
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_zero_denominator() {
+        Number::new(1, 0, true);
+    }
+
+    #[test]
+    fn test_equality_respects_sign() {
+        assert_eq!(Number::new(3, 4, true), Number::new(6, 8, true));
+        assert_ne!(Number::new(3, 4, true), Number::new(3, 4, false));
+        assert_eq!(Number::new(0, 1, true), Number::new(0, 3, false));
+    }
+
+    #[test]
+    fn test_clamp_min_max() {
+        let lo = Number::new(1, 2, true);
+        let hi = Number::new(7, 2, true);
+        assert_eq!(Number::new(5, 3, false).clamp(lo, hi), lo);
+        assert_eq!(Number::new(9, 2, true).clamp(lo, hi), hi);
+        assert_eq!(
+            Number::new(4, 3, true).clamp(lo, hi),
+            Number::new(4, 3, true)
+        );
+
+        let (lo, hi) = (Number::from(-3), Number::new(1, 3, false));
+        assert_eq!(Number::new(1, 4, true).clamp(lo, hi), hi);
+        assert_eq!(Number::from(-10).clamp(lo, hi), lo);
+        assert_eq!(Number::from(2).clamp(hi, hi), hi);
+
+        assert_eq!(
+            Number::new(1, 3, false).min(Number::new(1, 4, false)),
+            Number::new(1, 3, false)
+        );
+        assert_eq!(
+            Number::new(1, 3, false).max(Number::new(1, 4, false)),
+            Number::new(1, 4, false)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp range 1/2 > -1/2")]
+    fn test_clamp_inverted_range() {
+        let _ = Number::zero().clamp(Number::new(1, 2, true), Number::new(1, 2, false));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut numbers = vec![
+            Number::new(1, 2, true),
+            Number::new(3, 1, false),
+            Number::zero(),
+            Number::new(1, 3, false),
+            Number::new(2, 1, true),
+        ];
+        numbers.sort();
+
+        assert_eq!(
+            numbers,
+            vec![
+                Number::new(3, 1, false),
+                Number::new(1, 3, false),
+                Number::zero(),
+                Number::new(1, 2, true),
+                Number::new(2, 1, true),
+            ]
+        );
+        assert!(Number::new(1, 2, false) < Number::new(1, 3, false));
+    }
+
+    #[test]
+    fn test_hash_matches_eq() {
+        use std::collections::HashSet;
+        use std::hash::{BuildHasher, RandomState};
+
+        let equal = [
+            Number::new(1, 2, false),
+            Number::new(2, 4, false),
+            Number::ratio(3, -6),
+            -Number::new(50, 100, true),
+            Number::from((-7, 14)),
+        ];
+        let state = RandomState::new();
+        for n in &equal {
+            assert_eq!(n, &equal[0]);
+            assert_eq!(state.hash_one(n), state.hash_one(equal[0]));
+        }
+        assert_eq!(equal.iter().collect::<HashSet<_>>().len(), 1);
+
+        let distinct: HashSet<Number> = [Number::new(1, 2, true), Number::new(1, 2, false)]
+            .into_iter()
+            .collect();
+        assert_eq!(distinct.len(), 2);
+    }
+
+    #[test]
+    fn test_canonical_zero() {
+        let zero = Number::new(0, 7, false);
+        assert_eq!(*zero.denom(), 1);
+        assert_eq!(zero.to_string(), "0");
+        assert_eq!(
+            (Number::new(1, 2, true) - Number::new(1, 2, true)).to_string(),
+            "0"
+        );
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(Number::new(0, 1, true));
+        set.insert(Number::new(0, 1, false));
+        set.insert(-Number::zero());
+        assert_eq!(set.len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_f64() {
+        assert_eq!(Number::try_from(0.5).unwrap(), Number::new(1, 2, true));
+        assert_eq!(Number::try_from(-0.25).unwrap(), Number::new(1, 4, false));
+        assert_eq!(Number::try_from(0.1).unwrap(), Number::new(1, 10, true));
+        assert_eq!(Number::try_from(0.3).unwrap(), Number::new(3, 10, true));
+        assert_eq!(Number::try_from(3.0).unwrap(), Number::new(3, 1, true));
+        assert_eq!(
+            Number::try_from(-12.375).unwrap(),
+            Number::new(99, 8, false)
+        );
+        assert_eq!(
+            Number::try_from(1e10).unwrap(),
+            Number::from(10_000_000_000_i64)
+        );
+        assert_eq!(
+            Number::try_from(-4_294_967_296.5).unwrap(),
+            Number::ratio(-8_589_934_593, 2)
+        );
+        for bad in [f64::NAN, f64::INFINITY, -f64::INFINITY] {
+            assert_eq!(Number::try_from(bad), Err(PolysolveError::NotFinite));
+        }
+        for bad in [1e19, -9.3e18] {
+            assert_eq!(Number::try_from(bad), Err(PolysolveError::Overflow));
+        }
+    }
+
+    #[test]
+    fn signed_integer_ops() {
+        assert_eq!(Number::new(3, 1, true) * -2, Number::new(6, 1, false));
+        assert_eq!(Number::new(3, 4, false) * -2, Number::new(3, 2, true));
+        assert_eq!(Number::new(3, 1, true) / -2, Number::new(3, 2, false));
+        assert_eq!(Number::new(3, 5, false) / -3, Number::new(1, 5, true));
+        assert_eq!(Number::new(3, 5, false) / 3_i32, Number::new(1, 5, false));
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_integer_div_by_zero() {
+        let _ = Number::new(3, 1, true) / 0_i32;
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn compare_with_f64() {
+        assert!(Number::new(1, 2, true) == 0.5);
+        assert!(Number::new(1, 2, true) > 0.4);
+        assert!(Number::new(1, 2, true) < 0.6);
+        assert!(Number::new(1, 2, true) != 0.6);
+        assert!(Number::new(1, 2, true) != 0.5000001);
+        assert!(Number::new(1, 2, true) < 0.5000001);
+        assert!(Number::new(99, 8, false) == -12.375);
+        assert!(Number::new(1, 3, false) < -0.33);
+
+        // neither 1/10 nor 1/3 is a float
+        assert!(Number::new(1, 10, true) != 0.1);
+        assert!(Number::new(1, 10, true) < 0.1);
+        assert!(Number::new(1, 3, false) != -1.0 / 3.0);
+        assert_eq!(
+            Number::new(1, 3, true).partial_cmp(&(1.0 / 3.0)),
+            Some(Ordering::Greater)
+        );
+
+        // each sits strictly between its rounded float and the next one up
+        for n in [3_000_001, 2_000_001, 7] {
+            let x = Number::ratio(1, n);
+            let float = x.to_f64();
+            assert_eq!(x.partial_cmp(&float), Some(Ordering::Greater), "{}", n);
+            assert!(x < float.next_up(), "{}", n);
+            assert!(-x < -float && -x > -float.next_up(), "{}", n);
+        }
+        assert!(Number::ratio(1, 1 << 62) == 2.0_f64.powi(-62));
+        assert!(Number::ratio(-3, 1 << 40) == -3.0 * 2.0_f64.powi(-40));
+        assert!(Number::ratio(1, 1 << 62) > f64::MIN_POSITIVE);
+        assert!(Number::ratio(-1, 1 << 62) < -5e-324);
+
+        assert!(Number::from(2) == 2.0);
+        assert!(Number::from(2) != (2.0_f64).sqrt().powi(2));
+        assert!(Number::from(-(1_i64 << 53) - 1) < -9007199254740992.0);
+        assert!(Number::from(i64::MAX) < 9223372036854775807.0);
+        assert!(Number::from(i64::MAX) > 1e10);
+        assert!(Number::from(i64::MAX) < 1e300);
+        assert!(Number::from(i64::MAX) < f64::INFINITY);
+        assert!(Number::from(-i64::MAX) > f64::NEG_INFINITY);
+        assert!(Number::zero() == -0.0);
+        assert!(Number::zero() < 5e-324);
+        assert_eq!(Number::zero().partial_cmp(&f64::NAN), None);
+    }
+
+    #[test]
+    fn checked_ratio() {
+        assert_eq!(Number::checked_ratio(6, -8), Ok(Number::new(3, 4, false)));
+        assert_eq!(
+            Number::checked_ratio(i64::MIN, 2),
+            Ok(Number::from(-(1_i64 << 62)))
+        );
+        assert_eq!(
+            Number::checked_ratio(i64::MIN, 1),
+            Err(PolysolveError::Overflow)
+        );
+        assert_eq!(
+            Number::checked_ratio(1, i64::MIN),
+            Err(PolysolveError::Overflow)
+        );
+        assert_eq!(
+            Number::checked_ratio(1, 0),
+            Err(PolysolveError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let a = Number::new(3, 4, true);
+        let b = Number::new(5, 6, false);
+        assert_eq!(a.checked_add(b), Ok(a + b));
+        assert_eq!(a.checked_sub(b), Ok(a - b));
+        assert_eq!(a.checked_mul(b), Ok(a * b));
+        assert_eq!(a.checked_div(b), Ok(a / b));
+        assert_eq!(
+            a.checked_div(Number::zero()),
+            Err(PolysolveError::DivisionByZero)
+        );
+
+        let big = Number::from(i64::MAX);
+        assert_eq!(big.checked_add(1.into()), Err(PolysolveError::Overflow));
+        assert_eq!(big.checked_mul(2.into()), Err(PolysolveError::Overflow));
+        assert_eq!(
+            Number::from(-i64::MAX).checked_sub(1.into()),
+            Err(PolysolveError::Overflow)
+        );
+        assert_eq!(
+            Number::ratio(1, i64::MAX).checked_div(big),
+            Err(PolysolveError::Overflow)
+        );
+        // intermediate products may exceed i64 as long as the reduced result fits
+        assert_eq!(big.checked_mul(Number::ratio(2, i64::MAX)), Ok(2.into()));
+        assert_eq!(big.checked_sub(big), Ok(Number::zero()));
+    }
+
+    #[test]
+    fn test_pow_by_squaring() {
+        let two_thirds = Number::new(2, 3, true);
+        assert_eq!(two_thirds.pow(0), Number::one());
+        assert_eq!(two_thirds.pow(1), two_thirds);
+        assert_eq!(two_thirds.pow(20), Number::ratio(1 << 20, 3_i64.pow(20)));
+        assert_eq!(two_thirds.pow(-3), Number::new(27, 8, true));
+        assert_eq!(Number::new(1, 2, false).pow(5), Number::new(1, 32, false));
+        assert_eq!(Number::from(-1).pow(i32::MAX), Number::from(-1));
+        assert_eq!(Number::zero().pow(7), Number::zero());
+
+        assert_eq!(two_thirds.checked_pow(20), Ok(two_thirds.pow(20)));
+        // 3^40 is past i64::MAX
+        assert_eq!(two_thirds.checked_pow(40), Err(PolysolveError::Overflow));
+        assert_eq!(
+            Number::zero().checked_pow(-1),
+            Err(PolysolveError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Number overflow")]
+    fn test_pow_overflow() {
+        let _ = Number::new(2, 3, true).pow(40);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let third = Number::new(1, 3, true);
+        let close = Number::new(333_333, 1_000_000, true);
+        assert!(third.approx_eq(&close, 1e-6));
+        assert!(!third.approx_eq(&close, 1e-7));
+        assert!(third.approx_eq(&third, 0.0));
+        assert!(!third.approx_eq(&-third, 0.5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_f64_negative() {
+        assert_eq!(Number::try_from(-1.5).unwrap(), Number::new(3, 2, false));
+        assert_eq!(Number::try_from(-0.0).unwrap(), Number::zero());
+        assert!(!Number::try_from(-0.0).unwrap().is_negative());
+        for v in [-0.25, -1.5, -0.0, -0.999, -7.125, -1e-6, -123456.5] {
+            let back: f64 = Number::try_from(v).unwrap().into();
+            assert!((back - v).abs() < 1e-9, "{v}");
+            assert_eq!(Number::try_from(v).unwrap(), -Number::try_from(-v).unwrap());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_f64_non_terminating() {
+        let third = Number::try_from(1.0 / 3.0).unwrap();
+        assert!((Into::<f64>::into(third) - 1.0 / 3.0).abs() < 1e-9);
+
+        let root = Number::try_from(-std::f64::consts::SQRT_2).unwrap();
+        assert!((Into::<f64>::into(root) + std::f64::consts::SQRT_2).abs() < 1e-8);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_f64_approx() {
+        assert_eq!(
+            Number::from_f64_approx(0.3333333, 10).unwrap(),
+            Number::new(1, 3, true)
+        );
+        assert_eq!(
+            Number::from_f64_approx(-0.75, 100).unwrap(),
+            Number::new(3, 4, false)
+        );
+        assert_eq!(
+            Number::from_f64_approx(2.0, 5).unwrap(),
+            Number::new(2, 1, true)
+        );
+        assert_eq!(
+            Number::from_f64_approx(2.6, 1).unwrap(),
+            Number::new(3, 1, true)
+        );
+        assert_eq!(Number::from_f64_approx(0.0, 10).unwrap(), Number::zero());
+        assert_eq!(
+            Number::from_f64_approx(1e12, 10).unwrap(),
+            Number::from(1_000_000_000_000_i64)
+        );
+        assert_eq!(
+            Number::from_f64_approx(0.1, 10_000_000_000).unwrap(),
+            Number::new(1, 10, true)
+        );
+        for bad in [f64::NAN, f64::INFINITY, -f64::INFINITY] {
+            assert_eq!(
+                Number::from_f64_approx(bad, 10),
+                Err(PolysolveError::NotFinite)
+            );
+        }
+        for bad in [1e19, -9.3e18] {
+            assert_eq!(
+                Number::from_f64_approx(bad, 10),
+                Err(PolysolveError::Overflow)
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_f64_approx_pi() {
+        let pi = std::f64::consts::PI;
+        assert_eq!(
+            Number::from_f64_approx(pi, 10).unwrap(),
+            Number::new(22, 7, true)
+        );
+        assert_eq!(
+            Number::from_f64_approx(pi, 100).unwrap(),
+            Number::new(311, 99, true)
+        );
+        assert_eq!(
+            Number::from_f64_approx(pi, 1000).unwrap(),
+            Number::new(355, 113, true)
+        );
+        assert_eq!(
+            Number::from_f64_approx(-pi, 7).unwrap(),
+            Number::new(22, 7, false)
+        );
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(Number::new(3, 4, false).to_f64(), -0.75);
+        assert_eq!(Number::new(5, 2, true).to_f64(), 2.5);
+        assert_eq!(Number::zero().to_f64(), 0.0);
+        assert_eq!(Into::<f64>::into(Number::new(7, 8, false)), -0.875);
+        assert_eq!(Into::<f64>::into(Number::new(3, 4, false)), -0.75);
+        assert_eq!(Number::new(1, 4, false).to_f64(), -0.25);
+        assert_eq!(f64::from(Number::new(1, 4, false)), -0.25);
+        assert_eq!(Number::new(1, 4, false) + 1.0, 0.75);
+        assert_eq!(Number::new(1, 4, false) * 2.0, -0.5);
+    }
+
+    #[test]
+    fn test_reciprocal() {
+        assert_eq!(
+            Number::new(3, 4, false).reciprocal(),
+            Ok(Number::new(4, 3, false))
+        );
+        assert_eq!(
+            Number::new(5, 1, true).reciprocal(),
+            Ok(Number::new(1, 5, true))
+        );
+        assert_eq!(
+            Number::zero().reciprocal(),
+            Err(NumberError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_division() {}
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(
+            Number::new(12, 1, true).gcd(&Number::new(18, 1, false)),
+            Number::new(6, 1, true)
+        );
+        assert_eq!(
+            Number::new(1, 2, true).gcd(&Number::new(1, 3, true)),
+            Number::new(1, 6, true)
+        );
+        assert_eq!(
+            Number::new(4, 3, true).gcd(&Number::new(2, 9, true)),
+            Number::new(2, 9, true)
+        );
+        assert_eq!(
+            Number::zero().gcd(&Number::new(3, 4, true)),
+            Number::new(3, 4, true)
+        );
+    }
+
+    #[test]
+    fn test_multiplication() {
         assert_eq!(Number::new(4, 1, true) * 4, Number::new(16, 1, true));
         assert_eq!(
             Number::new(4, 1, true) * Number::new(4, 1, true),
@@ -245,10 +1467,325 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mul_div_assign() {
+        let factors = [
+            Number::new(2, 3, true),
+            Number::new(9, 4, false),
+            Number::new(5, 6, false),
+        ];
+
+        let mut product = Number::one();
+        for &f in &factors {
+            product *= f;
+        }
+        assert_eq!(product, factors[0] * factors[1] * factors[2]);
+        assert_eq!(product, Number::new(5, 4, true));
+
+        let mut quotient = product;
+        for &f in &factors {
+            quotient /= f;
+        }
+        assert_eq!(quotient, Number::one());
+        assert_eq!((*quotient.numer(), *quotient.denom()), (1, 1));
+    }
+
+    #[test]
+    fn test_ratio() {
+        assert_eq!(Number::ratio(-6, 8), Number::new(3, 4, false));
+        assert_eq!(Number::ratio(6, -8), Number::new(3, 4, false));
+        assert_eq!(Number::ratio(-6, -8), Number::new(3, 4, true));
+        let n = Number::ratio(0, -8);
+        assert_eq!((*n.numer(), *n.denom()), (0, 1));
+    }
+
+    #[test]
+    fn test_no_u32_overflow() {
+        let max = Number::new(u32::MAX, 1, true);
+        let half = Number::new(1 << 31, 1, false);
+        assert_eq!(*(half * half).numer(), 1 << 62);
+        assert_eq!(*(max + max).numer(), 2 * u32::MAX as i64);
+        assert_eq!(
+            (-max - max).to_string(),
+            format!("-{}", 2 * u32::MAX as i64)
+        );
+
+        let sum = Number::new(1, 65537, true) + Number::new(1, 65539, true);
+        assert_eq!((*sum.numer(), *sum.denom()), (131076, 65537 * 65539));
+
+        assert_eq!(*Number::new(3, 1 << 16, true).pow(3).denom(), 1 << 48);
+        assert!(max > Number::new(u32::MAX - 1, 1, true));
+    }
+
+    #[test]
+    fn test_factors_beyond_i32() {
+        let big = Number::new(1 << 31, 1, true);
+        let factors = big.factors();
+        assert!(factors.contains(&(1 << 31)) && factors.contains(&-(1 << 31)));
+        assert_eq!(factors.len(), 64);
+
+        let mut factors = Number::new(36, 1, false).factors();
+        factors.sort();
+        assert_eq!(
+            factors,
+            vec![-36, -18, -12, -9, -6, -4, -3, -2, -1, 1, 2, 3, 4, 6, 9, 12, 18, 36]
+        );
+    }
+
+    #[test]
+    fn factors_large_primes() {
+        // trial division would need about three billion steps for each of these
+        let mut factors = Number::from(9_223_372_036_854_775_783_i64).factors();
+        factors.sort();
+        assert_eq!(
+            factors,
+            vec![-9_223_372_036_854_775_783, -1, 1, 9_223_372_036_854_775_783]
+        );
+
+        let semiprime = 3_037_000_493_i64 * 3_037_000_453;
+        let mut divisors = Number::from(semiprime).numer().divisors();
+        divisors.sort();
+        assert_eq!(divisors, vec![1, 3_037_000_453, 3_037_000_493, semiprime]);
+
+        let mut divisors = (-(1_i128 << 40)).divisors();
+        divisors.sort();
+        assert_eq!(divisors, (0..=40).map(|k| 1_i128 << k).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Number overflow")]
+    fn test_overflow_panics() {
+        let max = Number::ratio(i64::MAX, 1);
+        let _ = max * max;
+    }
+
+    #[test]
+    fn test_ordering_without_overflow() {
+        let max = i64::MAX;
+        let a = Number::ratio(max, max - 1);
+        let b = Number::ratio(max - 1, max - 2);
+        assert!(a < b);
+        assert_eq!(b.cmp(&a), Ordering::Greater);
+        assert!(-a > -b);
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+        assert!(Number::ratio(max, 2) > Number::ratio(max - 2, 2));
+        assert!(Number::ratio(7, 3) < Number::ratio(5, 2));
+    }
+
+    #[test]
+    fn test_i128_backing() {
+        let big = Rational::<i128>::from(i64::MAX);
+        let squared = big * big;
+        assert_eq!(*squared.numer(), i64::MAX as i128 * i64::MAX as i128);
+        assert_eq!(squared / big, big);
+
+        let half = Rational::<i128>::from((1, 2));
+        assert_eq!((half + half).to_string(), "1");
+        assert_eq!(Rational::<i128>::from((-7, 2)).floor(), Rational::from(-4));
+        assert_eq!(half.pow(3), Rational::from((1, 8)));
+    }
+
+    #[test]
+    fn test_generic_over_backing() {
+        fn harmonic<I: Backing>(n: i32) -> Rational<I> {
+            (1..=n).fold(Rational::zero(), |acc, k| acc + Rational::from((1, k)))
+        }
+
+        assert_eq!(harmonic::<i64>(4), Number::ratio(25, 12));
+        assert_eq!(harmonic::<i128>(4), Rational::<i128>::ratio(25, 12));
+    }
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(
+            (Number::new(1, 2, true) - Number::new(3, 4, true)).to_string(),
+            "-1/4"
+        );
+        assert_eq!(
+            (Number::new(1, 2, false) - Number::new(3, 4, false)).to_string(),
+            "1/4"
+        );
+        assert_eq!(
+            (Number::new(2, 1, true) - Number::new(2, 1, true)).signum(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!((-Number::new(3, 4, true)).signum(), -1);
+        assert_eq!((-Number::new(3, 4, false)).signum(), 1);
+        assert_eq!(-Number::new(3, 4, true), Number::new(3, 4, false));
+    }
+
     #[test]
     fn test_pow() {
         assert_eq!(Number::new(4, 1, true).pow(2), Number::new(16, 1, true));
         assert_eq!(Number::new(1, 1, true).pow(2), Number::new(1, 1, true));
         assert_eq!(Number::new(4, 3, false).pow(3), Number::new(64, 27, false));
     }
+
+    #[test]
+    fn test_signum() {
+        assert_eq!(Number::new(3, 4, true).signum(), 1);
+        assert_eq!(Number::new(3, 4, false).signum(), -1);
+        assert_eq!(Number::new(0, 1, true).signum(), 0);
+        assert_eq!(Number::new(0, 1, false).signum(), 0);
+    }
+
+    #[test]
+    fn test_sign_predicates() {
+        let positive = Number::new(3, 4, true);
+        let negative = Number::new(3, 4, false);
+        assert!(positive.is_positive() && !positive.is_negative());
+        assert!(negative.is_negative() && !negative.is_positive());
+        assert!(!Number::zero().is_positive() && !Number::zero().is_negative());
+        assert!(!Number::new(0, 5, false).is_negative());
+    }
+
+    #[test]
+    fn test_floor_ceil_round() {
+        let half = Number::new(7, 2, true);
+        assert_eq!(half.floor(), Number::new(3, 1, true));
+        assert_eq!(half.ceil(), Number::new(4, 1, true));
+        assert_eq!(half.round(), Number::new(4, 1, true));
+
+        let negative_half = Number::new(7, 2, false);
+        assert_eq!(negative_half.floor(), Number::new(4, 1, false));
+        assert_eq!(negative_half.ceil(), Number::new(3, 1, false));
+        assert_eq!(negative_half.round(), Number::new(4, 1, false));
+
+        assert_eq!(Number::new(3, 2, false).floor(), Number::new(2, 1, false));
+        assert_eq!(Number::new(1, 3, false).round(), Number::zero());
+        assert_eq!(Number::new(1, 3, false).ceil(), Number::zero());
+        assert_eq!(Number::new(5, 3, true).round(), Number::new(2, 1, true));
+
+        let integer = Number::new(5, 1, false);
+        assert_eq!(integer.floor(), integer);
+        assert_eq!(integer.ceil(), integer);
+        assert_eq!(integer.round(), integer);
+        assert!(half.floor().is_integer() && negative_half.ceil().is_integer());
+    }
+
+    #[test]
+    fn test_from_i32() {
+        let n: Number = (-5).into();
+        assert_eq!(n, Number::new(5, 1, false));
+        assert_eq!(Number::from(7_i32), Number::new(7, 1, true));
+        assert_eq!(Number::from(0_i32), Number::zero());
+        assert_eq!(Number::from(i32::MIN), Number::new(1 << 31, 1, false));
+    }
+
+    #[test]
+    fn test_from_i32_pair() {
+        let n: Number = (-3, 4).into();
+        assert_eq!(n, Number::new(3, 4, false));
+        assert_eq!(Number::from((3, -4)), Number::new(3, 4, false));
+        assert_eq!(Number::from((-6, -4)), Number::new(3, 2, true));
+        assert_eq!(Number::from((0, -4)), Number::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "zero denominator")]
+    fn test_from_i32_pair_zero_denominator() {
+        let _ = Number::from((1, 0));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits_zero_one() {
+        use num_traits::{One, Zero};
+
+        assert_eq!(<Number as Zero>::zero(), Number::zero());
+        assert_eq!(<Number as One>::one(), Number::one());
+        assert!(Zero::is_zero(&Number::new(0, 7, false)));
+        assert!(!Zero::is_zero(&Number::new(1, 7, false)));
+        assert!(One::is_one(&Number::new(3, 3, true)));
+
+        let dot = [(1, 2), (3, 4)]
+            .iter()
+            .zip([(2, 1), (-4, 3)].iter())
+            .fold(<Number as Zero>::zero(), |acc, (&a, &b)| {
+                acc + Number::from(a) * Number::from(b)
+            });
+        assert_eq!(dot, Number::zero());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits_num() {
+        use num_traits::Num;
+
+        assert_eq!(
+            Number::from_str_radix("-3/4", 10),
+            Ok(Number::new(3, 4, false))
+        );
+        assert_eq!(
+            Number::from_str_radix("ff/10", 16),
+            Ok(Number::new(255, 16, true))
+        );
+        assert_eq!(
+            Number::from_str_radix("12", 10),
+            Ok(Number::new(12, 1, true))
+        );
+        assert_eq!(
+            Number::from_str_radix("1/0", 10),
+            Err(NumberError::ZeroDenominator)
+        );
+        assert_eq!(Number::from_str_radix("x", 10), Err(NumberError::Invalid));
+        for text in ["-9223372036854775808", "1/-9223372036854775808"] {
+            assert_eq!(Number::from_str_radix(text, 10), Err(NumberError::Invalid));
+        }
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits_generic_sum() {
+        fn sum<T: num_traits::Zero + Copy>(values: &[T]) -> T {
+            values.iter().fold(T::zero(), |acc, &v| acc + v)
+        }
+
+        let values = [
+            Number::new(1, 2, true),
+            Number::new(1, 3, true),
+            Number::new(5, 6, false),
+        ];
+        assert_eq!(sum(&values), Number::zero());
+        assert_eq!(sum::<Number>(&[]), Number::zero());
+        assert!(num_traits::Zero::is_zero(&sum(&values)));
+    }
+
+    #[test]
+    fn test_rem() {
+        assert_eq!(
+            Number::new(7, 2, true) % Number::new(1, 1, true),
+            Number::new(1, 2, true)
+        );
+        assert_eq!(
+            Number::new(7, 2, false) % Number::new(1, 1, true),
+            Number::new(1, 2, false)
+        );
+        assert_eq!(
+            Number::new(7, 1, true) % Number::new(3, 2, true),
+            Number::new(1, 1, true)
+        );
+        assert_eq!(
+            Number::new(3, 4, true) % Number::new(1, 4, true),
+            Number::zero()
+        );
+    }
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(Number::new(3, 4, false).abs(), Number::new(3, 4, true));
+        assert_eq!(Number::new(3, 4, true).abs(), Number::new(3, 4, true));
+        assert_eq!(Number::zero().abs(), Number::zero());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Number::new(6, 1, true).to_string(), "6");
+        assert_eq!(Number::new(6, 4, false).to_string(), "-3/2");
+        assert_eq!(Number::new(0, 5, false).to_string(), "0");
+    }
 }
@@ -0,0 +1,262 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+use core::str::{CharIndices, FromStr};
+
+use super::{Number, PolynomialFunction, Term};
+use crate::error::PolysolveError;
+
+fn parse_error(message: impl Into<String>) -> PolysolveError {
+    PolysolveError::ParseError(message.into())
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            chars: input.char_indices().peekable(),
+        }
+    }
+
+    fn position(&mut self) -> usize {
+        self.chars.peek().map_or(self.input.len(), |(i, _)| *i)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        self.chars.peek().map(|(_, c)| *c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.chars.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn unexpected(&mut self, expected: &str) -> PolysolveError {
+        let position = self.position();
+        match self.peek() {
+            Some(c) => parse_error(format!(
+                "expected {} at position {}, found '{}'",
+                expected, position, c
+            )),
+            None => parse_error(format!("expected {} at end of input", expected)),
+        }
+    }
+
+    // too large for `T` is an error too, rather than a silent wrap
+    fn integer<T: FromStr>(&mut self, expected: &str) -> Result<T, PolysolveError> {
+        if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            return Err(self.unexpected(expected));
+        }
+
+        let start = self.position();
+        while self.chars.next_if(|(_, c)| c.is_ascii_digit()).is_some() {}
+        let end = self.position();
+
+        self.input[start..end]
+            .parse()
+            .map_err(|_| parse_error(format!("{} at position {} is too large", expected, start)))
+    }
+
+    fn term(&mut self, positive: bool) -> Result<Term, PolysolveError> {
+        let coefficient = if self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            let numerator: i64 = self.integer("a coefficient")?;
+            let denominator = if self.eat('/') {
+                match self.integer("a denominator")? {
+                    0 => return Err(parse_error("denominator of zero")),
+                    d => d,
+                }
+            } else {
+                1
+            };
+            Some(Number::ratio(
+                if positive { numerator } else { -numerator },
+                denominator,
+            ))
+        } else {
+            None
+        };
+
+        if self.eat('x') {
+            let degree = if self.eat('^') {
+                self.integer("an exponent")?
+            } else {
+                1
+            };
+            let coefficient = coefficient.unwrap_or(Number::new(1, 1, positive));
+            Ok(Term::new(coefficient, degree))
+        } else {
+            match coefficient {
+                Some(coefficient) => Ok(Term::new(coefficient, 0)),
+                None => Err(self.unexpected("a coefficient or 'x'")),
+            }
+        }
+    }
+}
+
+impl FromStr for PolynomialFunction {
+    type Err = PolysolveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let mut terms = Vec::new();
+
+        if parser.peek().is_none() {
+            return Err(parse_error("empty input"));
+        }
+
+        let mut positive = !parser.eat('-');
+        if positive {
+            parser.eat('+');
+        }
+
+        loop {
+            terms.push(parser.term(positive)?);
+
+            if parser.peek().is_none() {
+                break;
+            }
+            positive = if parser.eat('+') {
+                true
+            } else if parser.eat('-') {
+                false
+            } else {
+                return Err(parser.unexpected("'+' or '-'"));
+            };
+        }
+
+        Ok(PolynomialFunction::new(terms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn parse() {
+        let func: PolynomialFunction = "2x^2 - 5x + 6".parse().unwrap();
+        assert_eq!(
+            func.terms,
+            vec![
+                Term::new(Number::new(2, 1, true), 2),
+                Term::new(Number::new(5, 1, false), 1),
+                Term::new(Number::new(6, 1, true), 0),
+            ]
+        );
+
+        let func: PolynomialFunction = "x^3 + 1/2 x".parse().unwrap();
+        assert_eq!(
+            func.terms,
+            vec![
+                Term::new(Number::new(1, 1, true), 3),
+                Term::new(Number::new(1, 2, true), 1),
+            ]
+        );
+
+        let func: PolynomialFunction = "7".parse().unwrap();
+        assert_eq!(func.terms, vec![Term::new(Number::new(7, 1, true), 0)]);
+
+        let func: PolynomialFunction = " - x^2+x-3/4 ".parse().unwrap();
+        assert_eq!(
+            func.terms,
+            vec![
+                Term::new(Number::new(1, 1, false), 2),
+                Term::new(Number::new(1, 1, true), 1),
+                Term::new(Number::new(3, 4, false), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for input in [
+            "2x^2 - 5x + 6",
+            "-x^3 + 1/2 x - 3/4",
+            "x^10 - x",
+            "7",
+            "-1",
+            "0",
+        ] {
+            let func: PolynomialFunction = input.parse().unwrap();
+            assert_eq!(func.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn reject_malformed() {
+        for input in [
+            "x^",
+            "2x^^2",
+            "",
+            "2x +",
+            "2 3x",
+            "1/0 x",
+            "y",
+            "x^-2",
+            "x^4294967295",
+            "x^2147483648",
+            "9223372036854775808x",
+            "1/9223372036854775808",
+        ] {
+            assert!(input.parse::<PolynomialFunction>().is_err(), "{}", input);
+        }
+
+        assert_eq!(
+            "x^".parse::<PolynomialFunction>().unwrap_err().to_string(),
+            "invalid polynomial: expected an exponent at end of input"
+        );
+        assert_eq!(
+            "2x^^2"
+                .parse::<PolynomialFunction>()
+                .unwrap_err()
+                .to_string(),
+            "invalid polynomial: expected an exponent at position 3, found '^'"
+        );
+        assert_eq!(
+            "x^4294967295"
+                .parse::<PolynomialFunction>()
+                .unwrap_err()
+                .to_string(),
+            "invalid polynomial: an exponent at position 2 is too large"
+        );
+
+        assert_eq!(
+            "9223372036854775808x"
+                .parse::<PolynomialFunction>()
+                .unwrap_err()
+                .to_string(),
+            "invalid polynomial: a coefficient at position 0 is too large"
+        );
+
+        let func: PolynomialFunction = "x^2147483647".parse().unwrap();
+        assert_eq!(func.degree(), Some(i32::MAX));
+    }
+
+    #[test]
+    fn parse_large_coefficients() {
+        let func: PolynomialFunction = "5000000000x^2 - 1/7000000000".parse().unwrap();
+        assert_eq!(
+            func.terms,
+            vec![
+                Term::new(Number::from(5_000_000_000_i64), 2),
+                Term::new(Number::ratio(-1, 7_000_000_000), 0),
+            ]
+        );
+        assert_eq!(func.to_string().parse::<PolynomialFunction>(), Ok(func));
+
+        for n in [5_000_000_000_i64, -5_000_000_000, i64::MAX, -i64::MAX] {
+            let constant = PolynomialFunction::constant(Number::from(n));
+            assert_eq!(constant.to_string().parse(), Ok(constant));
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul, Neg};
+
+use super::{Number, PolynomialFunction};
+use crate::error::PolysolveError;
+
+/// A quotient of two polynomials, kept in lowest terms with a monic denominator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RationalFunction {
+    numerator: PolynomialFunction,
+    denominator: PolynomialFunction,
+}
+
+impl RationalFunction {
+    /// Fails with [`PolysolveError::DivisionByZero`] if `denominator` is the zero
+    /// polynomial.
+    pub fn new(
+        numerator: PolynomialFunction,
+        denominator: PolynomialFunction,
+    ) -> Result<Self, PolysolveError> {
+        if denominator.degree().is_none() {
+            return Err(PolysolveError::DivisionByZero);
+        }
+        Ok(Self::reduced(numerator, denominator))
+    }
+
+    // divides out the gcd, then moves the denominator's leading coefficient upstairs
+    fn reduced(numerator: PolynomialFunction, denominator: PolynomialFunction) -> Self {
+        let common = numerator.gcd(&denominator);
+        let numerator = numerator.long_division(&common).0;
+        let denominator = denominator.long_division(&common).0;
+        let leading = denominator.leading_coefficient();
+        Self {
+            numerator: numerator.scale(Number::one() / leading),
+            denominator: denominator.scale(Number::one() / leading),
+        }
+    }
+
+    pub fn numerator(&self) -> &PolynomialFunction {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &PolynomialFunction {
+        &self.denominator
+    }
+
+    /// Splits into `(numerator, denominator)` pairs whose sum is `self`: any
+    /// polynomial part first over `1`, then `c / (x - r)^k` for each rational root
+    /// `r` of the denominator and each power up to its multiplicity, then whatever
+    /// is left over the part of the denominator with no rational roots. Fails if
+    /// finding those roots overflows.
+    pub fn partial_fractions(
+        &self,
+    ) -> Result<Vec<(PolynomialFunction, PolynomialFunction)>, PolysolveError> {
+        let one = PolynomialFunction::constant(Number::one());
+        let (quotient, mut numerator) = self.numerator.long_division(&self.denominator);
+        let mut rest = self.denominator.clone();
+        let mut fractions = Vec::new();
+        if quotient.degree().is_some() {
+            fractions.push((quotient, one.clone()));
+        }
+
+        for (root, multiplicity) in self.denominator.roots_with_multiplicity()? {
+            let factor = PolynomialFunction::from_coeffs(&[-root, Number::one()]);
+            let mut power = one.clone();
+            for _ in 0..multiplicity {
+                rest = rest.synthetic_divide(root).0;
+                power = &power * &factor;
+            }
+
+            // peel off c / (x - r)^k from the highest power down
+            let mut powers = Vec::new();
+            let at_root = rest.evaluate_exact(root);
+            for _ in 0..multiplicity {
+                let c = numerator.evaluate_exact(root) / at_root;
+                if c.not_zero() {
+                    powers.push((PolynomialFunction::from_coeffs(&[c]), power.clone()));
+                }
+                numerator = (numerator - rest.scale(c)).synthetic_divide(root).0;
+                power = power.synthetic_divide(root).0;
+            }
+            fractions.extend(powers.into_iter().rev());
+        }
+
+        if numerator.degree().is_some() {
+            fractions.push((numerator, rest));
+        }
+        Ok(fractions)
+    }
+
+    /// Exact value at `x`, or `None` at a pole.
+    pub fn evaluate_exact(&self, x: Number) -> Option<Number> {
+        let denominator = self.denominator.evaluate_exact(x);
+        denominator
+            .not_zero()
+            .then(|| self.numerator.evaluate_exact(x) / denominator)
+    }
+}
+
+impl From<PolynomialFunction> for RationalFunction {
+    fn from(value: PolynomialFunction) -> Self {
+        Self {
+            numerator: value,
+            denominator: PolynomialFunction::constant(Number::one()),
+        }
+    }
+}
+
+impl Add<&RationalFunction> for &RationalFunction {
+    type Output = RationalFunction;
+
+    fn add(self, rhs: &RationalFunction) -> Self::Output {
+        RationalFunction::reduced(
+            &self.numerator * &rhs.denominator + &rhs.numerator * &self.denominator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Add<RationalFunction> for RationalFunction {
+    type Output = RationalFunction;
+
+    fn add(self, rhs: RationalFunction) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl Mul<&RationalFunction> for &RationalFunction {
+    type Output = RationalFunction;
+
+    fn mul(self, rhs: &RationalFunction) -> Self::Output {
+        RationalFunction::reduced(
+            &self.numerator * &rhs.numerator,
+            &self.denominator * &rhs.denominator,
+        )
+    }
+}
+
+impl Mul<RationalFunction> for RationalFunction {
+    type Output = RationalFunction;
+
+    fn mul(self, rhs: RationalFunction) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl Neg for RationalFunction {
+    type Output = RationalFunction;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl fmt::Display for RationalFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator.degree() == Some(0) {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "({}) / ({})", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn poly(text: &str) -> PolynomialFunction {
+        text.parse().unwrap()
+    }
+
+    fn ratio(numerator: &str, denominator: &str) -> RationalFunction {
+        RationalFunction::new(poly(numerator), poly(denominator)).unwrap()
+    }
+
+    #[test]
+    fn simplify() {
+        let f = ratio("x^2 - 1", "x - 1");
+        assert_eq!(f.numerator(), &poly("x + 1"));
+        assert_eq!(f.denominator(), &poly("1"));
+        assert_eq!(f.to_string(), "x + 1");
+
+        // the denominator is made monic
+        let f = ratio("4x", "2x^2 + 2");
+        assert_eq!(f.to_string(), "(2x) / (x^2 + 1)");
+    }
+
+    #[test]
+    fn zero_denominator() {
+        assert_eq!(
+            RationalFunction::new(poly("x"), PolynomialFunction::new(Vec::new())),
+            Err(PolysolveError::DivisionByZero)
+        );
+        let zero = ratio("0", "x + 3");
+        assert_eq!(zero.denominator(), &poly("1"));
+    }
+
+    #[test]
+    fn add() {
+        // 1/x + 1/(x + 1) = (2x + 1)/(x^2 + x)
+        let sum = ratio("1", "x") + ratio("1", "x + 1");
+        assert_eq!(sum, ratio("2x + 1", "x^2 + x"));
+
+        // 1/(x - 1) - 1/(x + 1) = 2/(x^2 - 1)
+        let difference = ratio("1", "x - 1") + -ratio("1", "x + 1");
+        assert_eq!(difference.to_string(), "(2) / (x^2 - 1)");
+        assert_eq!(
+            (ratio("x", "x + 1") + -ratio("x", "x + 1")).to_string(),
+            "0"
+        );
+    }
+
+    #[test]
+    fn mul() {
+        let product = ratio("x", "x + 1") * ratio("x + 1", "x^2");
+        assert_eq!(product, ratio("1", "x"));
+        assert_eq!(
+            product.evaluate_exact(Number::new(1, 2, true)),
+            Some(2.into())
+        );
+        assert_eq!(product.evaluate_exact(Number::zero()), None);
+    }
+
+    #[test]
+    fn partial_fractions() {
+        // 1/((x - 1)(x - 2)) = -1/(x - 1) + 1/(x - 2)
+        let f = ratio("1", "x^2 - 3x + 2");
+        assert_eq!(
+            f.partial_fractions().unwrap(),
+            vec![(poly("-1"), poly("x - 1")), (poly("1"), poly("x - 2"))]
+        );
+
+        // 1/((x + 1)(x - 1)^2) = (1/4)/(x + 1) - (1/4)/(x - 1) + (1/2)/(x - 1)^2
+        let f = ratio("1", "x^3 - x^2 - x + 1");
+        assert_eq!(
+            f.partial_fractions().unwrap(),
+            vec![
+                (poly("1/4"), poly("x + 1")),
+                (poly("-1/4"), poly("x - 1")),
+                (poly("1/2"), poly("x^2 - 2x + 1")),
+            ]
+        );
+
+        // improper, and with a factor that has no rational roots
+        for f in [ratio("x^3", "x^2 - 1"), ratio("1", "x^3 - x^2 + x - 1")] {
+            let sum = f
+                .partial_fractions()
+                .unwrap()
+                .into_iter()
+                .map(|(n, d)| RationalFunction::new(n, d).unwrap())
+                .fold(RationalFunction::from(poly("0")), |acc, g| acc + g);
+            assert_eq!(sum, f);
+        }
+        assert_eq!(
+            ratio("1", "x^3 - x^2 + x - 1").partial_fractions().unwrap(),
+            vec![
+                (poly("1/2"), poly("x - 1")),
+                (poly("-1/2x - 1/2"), poly("x^2 + 1"))
+            ]
+        );
+        assert_eq!(
+            ratio("x^3", "x^2 - 1").partial_fractions().unwrap()[0],
+            (poly("x"), poly("1"))
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Number, NumberError, PolynomialFunction, Term};
+
+// Numbers go out in their Display form ("-3/4", "5") so the JSON stays readable
+impl Serialize for Number {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Number {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        let invalid = || D::Error::custom(format!("invalid number {:?}", text));
+        let (numerator, denominator) = match text.split_once('/') {
+            Some((n, d)) => (n.trim(), d.trim()),
+            None => (text.trim(), "1"),
+        };
+        let numerator: i64 = numerator.parse().map_err(|_| invalid())?;
+        let denominator: i64 = denominator.parse().map_err(|_| invalid())?;
+        if denominator == 0 {
+            return Err(D::Error::custom(NumberError::ZeroDenominator));
+        }
+        Number::checked_ratio(numerator, denominator).map_err(D::Error::custom)
+    }
+}
+
+// polynomials go out as `(degree, coefficient)` pairs, highest degree first
+impl Serialize for PolynomialFunction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter_terms())
+    }
+}
+
+impl<'de> Deserialize<'de> for PolynomialFunction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pairs = Vec::<(i32, Number)>::deserialize(deserializer)?;
+        if let Some((degree, _)) = pairs.iter().find(|(degree, _)| *degree < 0) {
+            return Err(D::Error::custom(format!("negative degree {}", degree)));
+        }
+        Ok(PolynomialFunction::new(
+            pairs
+                .into_iter()
+                .map(|(degree, coefficient)| Term::new(coefficient, degree))
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_number() {
+        for n in [
+            Number::new(3, 4, false),
+            Number::new(5, 1, true),
+            Number::zero(),
+            Number::ratio(i64::MAX, 7),
+        ] {
+            let json = serde_json::to_string(&n).unwrap();
+            assert_eq!(serde_json::from_str::<Number>(&json).unwrap(), n);
+        }
+        assert_eq!(
+            serde_json::to_string(&Number::new(3, 4, false)).unwrap(),
+            r#""-3/4""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Number::new(5, 1, true)).unwrap(),
+            r#""5""#
+        );
+    }
+
+    #[test]
+    fn deserialize_number_simplifies() {
+        let n: Number = serde_json::from_str(r#""6/-8""#).unwrap();
+        assert_eq!((*n.numer(), *n.denom()), (-3, 4));
+        assert!(serde_json::from_str::<Number>(r#""1/0""#).is_err());
+        assert!(serde_json::from_str::<Number>(r#""one half""#).is_err());
+        assert!(serde_json::from_str::<Number>("12").is_err());
+    }
+
+    #[test]
+    fn deserialize_number_out_of_range() {
+        for text in [r#""-9223372036854775808""#, r#""1/-9223372036854775808""#] {
+            let error = serde_json::from_str::<Number>(text).unwrap_err();
+            assert_eq!(error.to_string(), "Number overflow");
+        }
+        let n: Number = serde_json::from_str(r#""-9223372036854775808/2""#).unwrap();
+        assert_eq!(n, Number::from(-(1_i64 << 62)));
+    }
+
+    #[test]
+    fn roundtrip_polynomial() {
+        let func: PolynomialFunction = "1/2 x^3 - 2/3 x + 5".parse().unwrap();
+        let json = serde_json::to_string(&func).unwrap();
+        assert_eq!(json, r#"[[3,"1/2"],[1,"-2/3"],[0,"5"]]"#);
+        assert_eq!(
+            serde_json::from_str::<PolynomialFunction>(&json).unwrap(),
+            func
+        );
+    }
+
+    #[test]
+    fn roundtrip_sparse_polynomial() {
+        let func: PolynomialFunction = "-x^40 + 7x^3".parse().unwrap();
+        let json = serde_json::to_string(&func).unwrap();
+        assert_eq!(json, r#"[[40,"-1"],[3,"7"]]"#);
+        assert_eq!(
+            serde_json::from_str::<PolynomialFunction>(&json).unwrap(),
+            func
+        );
+    }
+
+    #[test]
+    fn roundtrip_zero_polynomial() {
+        let zero = PolynomialFunction::new(Vec::new());
+        let json = serde_json::to_string(&zero).unwrap();
+        assert_eq!(json, "[]");
+        assert_eq!(
+            serde_json::from_str::<PolynomialFunction>(&json).unwrap(),
+            zero
+        );
+    }
+
+    #[test]
+    fn deserialize_polynomial_simplifies() {
+        let json = r#"[[0,"1"],[2,"2"],[0,"1"],[5,"0"]]"#;
+        let func: PolynomialFunction = serde_json::from_str(json).unwrap();
+        assert_eq!(func, "2x^2 + 2".parse().unwrap());
+
+        let cancelled: PolynomialFunction = serde_json::from_str(r#"[[1,"3"],[1,"-3"]]"#).unwrap();
+        assert_eq!(cancelled, PolynomialFunction::new(Vec::new()));
+
+        let negative = serde_json::from_str::<PolynomialFunction>(r#"[[2,"1"],[-1,"3"]]"#);
+        assert_eq!(negative.unwrap_err().to_string(), "negative degree -1");
+    }
+}
@@ -0,0 +1,29 @@
+// A `#![no_std]` crate root using the exact parts of the API, to keep them
+// usable without `std`. `cargo build --lib --no-default-features` covers the
+// library side.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+use alloc::vec;
+
+use polysolve::types::{Number, PolynomialFunction};
+
+#[test]
+fn exact_core_without_std() {
+    let func: PolynomialFunction = "x^2 - 1/4".parse().unwrap();
+    assert_eq!(
+        func.roots(),
+        Ok(vec![Number::new(1, 2, false), Number::new(1, 2, true)])
+    );
+    assert_eq!(
+        func.evaluate_exact(Number::new(3, 2, true)),
+        Number::from(2)
+    );
+    assert_eq!(func.derivative().to_string(), "2x");
+    assert_eq!(
+        (Number::new(1, 3, true) + Number::new(1, 6, true)).to_string(),
+        "1/2"
+    );
+}